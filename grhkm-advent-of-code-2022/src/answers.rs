@@ -0,0 +1,152 @@
+use crate::history::{escape, unescape};
+use crate::puzzle::Puzzle;
+use crate::solver::Output;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Confirmed answers for our own inputs, stored as one tab separated line per part
+pub struct KnownAnswers {
+    path: PathBuf,
+    answers: BTreeMap<(Puzzle, usize), String>,
+}
+
+impl KnownAnswers {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<KnownAnswers> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let (puzzle, level, answer) = decode_answer(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{path:?}:{}: malformed answer {line:?}", i + 1),
+                )
+            })?;
+            answers.insert((puzzle, level), answer);
+        }
+
+        Ok(KnownAnswers { path, answers })
+    }
+
+    pub fn get(&self, puzzle: Puzzle, level: usize) -> Option<&str> {
+        self.answers.get(&(puzzle, level)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, puzzle: Puzzle, level: usize, answer: String) {
+        self.answers.insert((puzzle, level), answer);
+    }
+
+    pub fn puzzles(&self) -> Vec<Puzzle> {
+        let mut puzzles = self.answers.keys().map(|&(p, _)| p).collect::<Vec<_>>();
+        puzzles.dedup();
+        puzzles
+    }
+
+    // The parts with a known answer, as taken by `--part`
+    pub fn part(&self, puzzle: Puzzle) -> Option<usize> {
+        match (self.get(puzzle, 1), self.get(puzzle, 2)) {
+            (Some(_), None) => Some(1),
+            (None, Some(_)) => Some(2),
+            _ => None,
+        }
+    }
+
+    // Rewrites the whole file, sorted by puzzle and part
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = self
+            .answers
+            .iter()
+            .map(|(&(puzzle, level), answer)| {
+                format!(
+                    "{}\t{}\t{level}\t{}\n",
+                    puzzle.year,
+                    puzzle.day,
+                    escape(answer)
+                )
+            })
+            .collect::<String>();
+        fs::write(&self.path, contents)
+    }
+
+    // Compares every part in `output` that has a known answer, except the drawn ones
+    pub fn check(&self, puzzle: Puzzle, output: &Output) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+        for (level, answer) in [(1, &output.part1), (2, &output.part2)] {
+            let (Some(expected), Some(answer)) = (self.get(puzzle, level), answer) else {
+                continue;
+            };
+            if comparable(expected, &answer.answer) && expected != answer.answer {
+                mismatches.push(Mismatch {
+                    puzzle,
+                    level,
+                    expected: expected.to_string(),
+                    actual: answer.answer.clone(),
+                });
+            }
+        }
+        mismatches
+    }
+
+    // The parts in `output` with a known answer that `check` can't compare
+    pub fn unchecked(&self, puzzle: Puzzle, output: &Output) -> Vec<usize> {
+        [(1, &output.part1), (2, &output.part2)]
+            .into_iter()
+            .filter(|(level, answer)| match (self.get(puzzle, *level), answer) {
+                (Some(expected), Some(answer)) => !comparable(expected, &answer.answer),
+                _ => false,
+            })
+            .map(|(level, _)| level)
+            .collect()
+    }
+}
+
+// Some answers are letters drawn in ASCII art, which the site has read off for us when
+// it shows them, so they only compare against other drawings
+fn comparable(expected: &str, actual: &str) -> bool {
+    let drawn = |answer: &str| answer.trim().contains('\n');
+    drawn(expected) == drawn(actual)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub puzzle: Puzzle,
+    pub level: usize,
+    pub expected: String,
+    pub actual: String,
+}
+
+// Shown as a diff, so that multi-line answers line up
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} level {}:", self.puzzle, self.level)?;
+        for line in self.expected.trim_matches('\n').lines() {
+            writeln!(f, "- {line}")?;
+        }
+        for line in self.actual.trim_matches('\n').lines() {
+            writeln!(f, "+ {line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn decode_answer(line: &str) -> Option<(Puzzle, usize, String)> {
+    match line.split('\t').collect::<Vec<_>>()[..] {
+        [year, day, level, answer] => Some((
+            Puzzle::new(year.parse().ok()?, day.parse().ok()?),
+            level.parse().ok()?,
+            unescape(answer),
+        )),
+        _ => None,
+    }
+}
@@ -0,0 +1,214 @@
+use crate::format::{self, Format};
+use crate::parser::ParseResult;
+use crate::puzzle::Puzzle;
+use crate::runner::{self, Status};
+use crate::solver::Solver;
+use serde::Serialize;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Stats {
+    pub year: usize,
+    pub day: usize,
+    pub runs: usize,
+    pub min_us: u128,
+    pub median_us: u128,
+    pub mean_us: u128,
+    pub p95_us: u128,
+}
+
+impl Stats {
+    pub fn new(puzzle: Puzzle, samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "err: No samples for {puzzle}");
+
+        let mut samples = samples.iter().map(Duration::as_micros).collect::<Vec<_>>();
+        samples.sort_unstable();
+
+        let n = samples.len();
+        // nearest rank, i.e. the smallest sample with at least 95% of samples below or at it
+        let p95 = (n * 95).div_ceil(100);
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+
+        Stats {
+            year: puzzle.year,
+            day: puzzle.day,
+            runs: n,
+            min_us: samples[0],
+            median_us: median,
+            mean_us: samples.iter().sum::<u128>() / n as u128,
+            p95_us: samples[p95 - 1],
+        }
+    }
+}
+
+// How benchmarking one day went, with timings only if every run got through
+#[derive(Clone, Debug)]
+pub struct Benchmark {
+    pub puzzle: Puzzle,
+    pub status: Status,
+    pub stats: Option<Stats>,
+}
+
+impl Benchmark {
+    pub fn fetch_failed(puzzle: Puzzle, msg: String) -> Benchmark {
+        Benchmark {
+            puzzle,
+            status: Status::FetchFailed(msg),
+            stats: None,
+        }
+    }
+}
+
+fn measure(
+    solver: Solver,
+    contents: &str,
+    part: Option<usize>,
+    runs: usize,
+) -> ParseResult<Vec<Duration>> {
+    // warm up caches and lazy statics before measuring anything, and bail
+    // out if the input is malformed as every run would fail the same way
+    solver(contents, part)?;
+    for _ in 1..runs.div_ceil(10) {
+        black_box(solver(black_box(contents), part)).ok();
+    }
+
+    Ok((0..runs)
+        .map(|_| {
+            let now = Instant::now();
+            black_box(solver(black_box(contents), part)).ok();
+            now.elapsed()
+        })
+        .collect())
+}
+
+// Times `runs` runs of a solution, a panicking one taking down only its own day
+pub fn bench(
+    puzzle: Puzzle,
+    solver: Solver,
+    contents: &str,
+    part: Option<usize>,
+    runs: usize,
+) -> Benchmark {
+    let (status, stats) = match runner::catch_panic(|| measure(solver, contents, part, runs)) {
+        Ok(Ok(samples)) => (Status::Ok, Some(Stats::new(puzzle, &samples))),
+        Ok(Err(e)) => (Status::InvalidInput(e), None),
+        Err(status) => (status, None),
+    };
+    Benchmark {
+        puzzle,
+        status,
+        stats,
+    }
+}
+
+// One row per day, followed by the messages of the days that failed
+pub fn format_table(benchmarks: &[Benchmark]) -> String {
+    let mut res = format!(
+        "{:<17} {:<15} {:>6} {:>12} {:>12} {:>12} {:>12}\n",
+        "Puzzle", "Status", "Runs", "Min (µs)", "Median (µs)", "Mean (µs)", "P95 (µs)"
+    );
+    for b in benchmarks {
+        let puzzle = b.puzzle.to_string();
+        let timings = match &b.stats {
+            Some(s) => {
+                [s.runs as u128, s.min_us, s.median_us, s.mean_us, s.p95_us].map(|n| n.to_string())
+            }
+            None => ["-"; 5].map(String::from),
+        };
+        let [runs, min, median, mean, p95] = timings;
+        res += &format!(
+            "{puzzle:<17} {:<15} {runs:>6} {min:>12} {median:>12} {mean:>12} {p95:>12}\n",
+            b.status.label()
+        );
+    }
+    for b in benchmarks.iter().filter(|b| b.status.is_failure()) {
+        res += &format!("{}: {}\n", b.puzzle, b.status);
+        if let Status::InvalidInput(e) = &b.status {
+            res += &format!("{}\n", e.caret());
+        }
+    }
+    res
+}
+
+// A benchmark flattened into plain fields, for the machine-readable formats
+#[derive(Clone, Debug, Serialize)]
+pub struct Row {
+    pub year: usize,
+    pub day: usize,
+    pub status: &'static str,
+    pub message: Option<String>,
+    pub runs: Option<usize>,
+    pub min_us: Option<u128>,
+    pub median_us: Option<u128>,
+    pub mean_us: Option<u128>,
+    pub p95_us: Option<u128>,
+}
+
+const FIELDS: [&str; 9] = [
+    "year",
+    "day",
+    "status",
+    "message",
+    "runs",
+    "min_us",
+    "median_us",
+    "mean_us",
+    "p95_us",
+];
+
+impl Row {
+    pub fn new(benchmark: &Benchmark) -> Row {
+        let stats = benchmark.stats.as_ref();
+        Row {
+            year: benchmark.puzzle.year,
+            day: benchmark.puzzle.day,
+            status: benchmark.status.label(),
+            message: benchmark.status.message(),
+            runs: stats.map(|s| s.runs),
+            min_us: stats.map(|s| s.min_us),
+            median_us: stats.map(|s| s.median_us),
+            mean_us: stats.map(|s| s.mean_us),
+            p95_us: stats.map(|s| s.p95_us),
+        }
+    }
+
+    fn fields(&self) -> Vec<String> {
+        let num = |v: Option<u128>| v.map_or(String::new(), |v| v.to_string());
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.status.to_string(),
+            self.message.clone().unwrap_or_default(),
+            num(self.runs.map(|n| n as u128)),
+            num(self.min_us),
+            num(self.median_us),
+            num(self.mean_us),
+            num(self.p95_us),
+        ]
+    }
+}
+
+pub fn format_json(benchmarks: &[Benchmark]) -> String {
+    let rows = benchmarks.iter().map(Row::new).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&rows).unwrap()
+}
+
+pub fn format(benchmarks: &[Benchmark], format: Format) -> String {
+    let fields = || {
+        benchmarks
+            .iter()
+            .map(|b| Row::new(b).fields())
+            .collect::<Vec<_>>()
+    };
+    match format {
+        Format::Table => format_table(benchmarks),
+        Format::Json => format_json(benchmarks) + "\n",
+        Format::Csv => format::csv(&FIELDS, &fields()),
+        Format::Markdown => format::markdown(&FIELDS, &fields()),
+    }
+}
@@ -0,0 +1,63 @@
+use crate::error::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+// Server responses saved on disk, laid out like the URLs they came from
+#[derive(Clone, Debug)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    // `None` for paths that can't be stored safely, such as ones going up with `..`
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        let key = key.trim_start_matches('/');
+        let safe = !key.is_empty()
+            && key
+                .split('/')
+                .all(|part| !part.is_empty() && part != "." && part != "..");
+        // a suffix, as a page like `/2022/day/1` is also the directory of `/2022/day/1/input`
+        safe.then(|| self.dir.join(format!("{key}.cache")))
+    }
+
+    // The saved response, unless it is older than `max_age`
+    pub fn get(&self, key: &str, max_age: Option<Duration>) -> Option<String> {
+        let path = self.path(key)?;
+        if let Some(max_age) = max_age {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            let age = SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default();
+            if age > max_age {
+                return None;
+            }
+        }
+        fs::read_to_string(path).ok()
+    }
+
+    pub fn put(&self, key: &str, contents: &str) -> Result<()> {
+        let Some(path) = self.path(key) else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("Creating {parent:?}"))?;
+        }
+        fs::write(&path, contents).with_context(|| format!("Caching {path:?}"))
+    }
+
+    // Forgets a response that is known to have changed
+    pub fn remove(&self, key: &str) {
+        if let Some(path) = self.path(key) {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
@@ -0,0 +1,202 @@
+use crate::consts;
+use crate::error::{Context, Error, Result};
+use crate::format::Format;
+use crate::puzzle::Puzzle;
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use toml::{Table, Value};
+
+// Settings shared by every command, from `aoc.toml` in the project and then
+// the user's own `config.toml`. Command line flags take precedence over both
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    // Where inputs, cached pages and answer files are kept
+    pub input_dir: PathBuf,
+    // Where example inputs are kept, `{input_dir}/{year}/sample` if unset
+    pub sample_dir: Option<PathBuf>,
+    pub year: usize,
+    // A session file to use in place of the per-user one, so `--cookie` and
+    // `AOC_SESSION` still take precedence
+    pub session_file: Option<PathBuf>,
+    #[serde(deserialize_with = "from_str")]
+    pub format: Format,
+    // How long to give a solution before giving up on it
+    #[serde(deserialize_with = "seconds")]
+    pub timeout: Option<Duration>,
+    // Whether to ask before submitting an answer
+    pub confirm_submit: bool,
+    pub http: HttpConfig,
+}
+
+// How we talk to the server, kept polite as the automation guidelines ask
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct HttpConfig {
+    // Should say who to contact about misbehaving requests
+    pub user_agent: String,
+    // The least time to leave between two requests
+    #[serde(deserialize_with = "interval")]
+    pub interval: Duration,
+    #[serde(deserialize_with = "seconds")]
+    pub timeout: Option<Duration>,
+    // Where responses are kept so they are only downloaded once, `{input_dir}/cache`
+    // if unset
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> HttpConfig {
+        HttpConfig {
+            user_agent: consts::USER_AGENT.to_string(),
+            interval: Duration::from_secs(3),
+            timeout: Some(Duration::from_secs(30)),
+            cache_dir: None,
+        }
+    }
+}
+
+impl HttpConfig {
+    // An email address or a URL, the default user agent has neither
+    pub fn has_contact(&self) -> bool {
+        self.user_agent.contains('@') || self.user_agent.contains("://")
+    }
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            input_dir: PathBuf::from("./input"),
+            sample_dir: None,
+            year: consts::DEFAULT_YEAR,
+            session_file: None,
+            format: Format::Table,
+            timeout: None,
+            confirm_submit: true,
+            http: HttpConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    // The project config, overridden by the user's
+    pub fn load() -> Result<Config> {
+        let mut paths = vec![PathBuf::from(consts::CONFIG_FILE)];
+        paths.extend(user_dir().map(|dir| dir.join(consts::USER_CONFIG_FILE)));
+        Config::from_files(&paths)
+    }
+
+    // Later files override earlier ones key by key, missing files are skipped
+    pub fn from_files<P: AsRef<Path>>(paths: &[P]) -> Result<Config> {
+        let mut table = Table::new();
+        for path in paths {
+            let path = path.as_ref();
+            let contents = match fs::read_to_string(path) {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e).with_context(|| format!("Reading config {path:?}")),
+            };
+            let layer = contents
+                .parse::<Table>()
+                .map_err(|e| Error::Parse(e.to_string()))
+                .with_context(|| format!("Reading config {path:?}"))?;
+            merge(&mut table, layer);
+        }
+
+        Config::deserialize(Value::Table(table))
+            .map_err(|e| Error::Parse(e.to_string().trim_end().replace('\n', " ")))
+            .context("Reading config")
+    }
+
+    pub fn input_path(&self, puzzle: Puzzle) -> PathBuf {
+        let Puzzle { year, day } = puzzle;
+        self.input_dir.join(format!("{year}/day_{day:02}.in"))
+    }
+
+    pub fn sample_path(&self, puzzle: Puzzle) -> PathBuf {
+        let Puzzle { year, day } = puzzle;
+        match &self.sample_dir {
+            Some(dir) => dir.join(format!("{year}/day_{day:02}.in")),
+            None => self
+                .input_dir
+                .join(format!("{year}/sample/day_{day:02}.in")),
+        }
+    }
+
+    pub fn history_file(&self) -> PathBuf {
+        self.input_dir.join("history.tsv")
+    }
+
+    pub fn answers_file(&self) -> PathBuf {
+        self.input_dir.join("answers.tsv")
+    }
+
+    // The HTTP settings with the cache directory filled in
+    pub fn http(&self) -> HttpConfig {
+        let cache_dir = self.http.cache_dir.clone();
+        HttpConfig {
+            cache_dir: cache_dir.or_else(|| Some(self.input_dir.join("cache"))),
+            ..self.http.clone()
+        }
+    }
+}
+
+// The per-user config directory, under `$XDG_CONFIG_HOME` or `~/.config`
+pub fn user_dir() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join(consts::CONFIG_DIR))
+}
+
+fn merge(base: &mut Table, layer: Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base)), Value::Table(layer)) => merge(base, layer),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+pub(crate) fn from_str<'de, D: Deserializer<'de>, T: FromStr>(
+    deserializer: D,
+) -> std::result::Result<T, D::Error>
+where
+    T::Err: Display,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+fn seconds<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Duration>, D::Error> {
+    let secs = f64::deserialize(deserializer)?;
+    Duration::try_from_secs_f64(secs)
+        .ok()
+        .filter(|timeout| !timeout.is_zero())
+        .map(Some)
+        .ok_or_else(|| {
+            de::Error::custom(format!(
+                "timeout must be a positive number of seconds, not {secs}"
+            ))
+        })
+}
+
+// Unlike a timeout, no interval at all is fine
+fn interval<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Duration, D::Error> {
+    let secs = f64::deserialize(deserializer)?;
+    Duration::try_from_secs_f64(secs)
+        .map_err(|_| de::Error::custom(format!("interval must be a number of seconds, not {secs}")))
+}
@@ -1 +1,9 @@
-pub const YEAR: usize = 2022;
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const FIRST_YEAR: usize = 2015;
+pub const DEFAULT_YEAR: usize = 2022;
+pub const CONFIG_FILE: &str = "./aoc.toml";
+pub const CONFIG_DIR: &str = "aoc";
+pub const USER_CONFIG_FILE: &str = "config.toml";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = "session";
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
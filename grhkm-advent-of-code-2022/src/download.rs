@@ -0,0 +1,86 @@
+use crate::config::Config;
+use crate::error::{Context, Error, Result};
+use crate::fetcher::Fetcher;
+use crate::page;
+use crate::puzzle::Puzzle;
+use crate::solver;
+use std::fmt;
+use std::fs;
+
+// What became of one input in a bulk download
+#[derive(Debug)]
+pub enum Outcome {
+    Fetched,
+    Cached,
+    Locked,
+    Failed(Error),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Fetched => write!(f, "Fetched"),
+            Outcome::Cached => write!(f, "Already cached"),
+            Outcome::Locked => write!(f, "Not unlocked yet"),
+            Outcome::Failed(e) => write!(f, "Failed: {e}"),
+        }
+    }
+}
+
+// Parses a list of days such as "1-25" or "1,3,5-7"
+pub fn parse_days(spec: &str) -> Result<Vec<usize>> {
+    let invalid = || Error::Usage(format!("Invalid days {spec:?}, expected e.g. \"1-25\""));
+    let mut days = Vec::new();
+    for part in spec.split(',').map(str::trim) {
+        let (first, last) = part.split_once('-').unwrap_or((part, part));
+        let first: usize = first.trim().parse().map_err(|_| invalid())?;
+        let last: usize = last.trim().parse().map_err(|_| invalid())?;
+        if first < 1 || first > last || last > solver::DAYS {
+            return Err(invalid());
+        }
+        days.extend(first..=last);
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+// Downloads the inputs of the given days that aren't saved yet, leaving out the ones the
+// calendar doesn't link to yet. The calendar is only asked for if something is missing
+pub fn download(
+    fetcher: &Fetcher,
+    config: &Config,
+    year: usize,
+    days: &[usize],
+) -> Result<Vec<(Puzzle, Outcome)>> {
+    let mut unlocked = None;
+    let mut outcomes = Vec::new();
+    for puzzle in days.iter().map(|&day| Puzzle::new(year, day)) {
+        let path = config.input_path(puzzle);
+        if path.is_file() {
+            outcomes.push((puzzle, Outcome::Cached));
+            continue;
+        }
+
+        if unlocked.is_none() {
+            let calendar = fetcher.calendar(year)?;
+            unlocked = Some(page::calendar(&calendar));
+        }
+        if !unlocked.iter().flatten().any(|&(day, _)| day == puzzle.day) {
+            outcomes.push((puzzle, Outcome::Locked));
+            continue;
+        }
+
+        let outcome = fetcher.fetch(puzzle).and_then(|input| {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).with_context(|| format!("Creating {parent:?}"))?;
+            }
+            fs::write(&path, input).with_context(|| format!("Writing to {path:?}"))
+        });
+        outcomes.push((
+            puzzle,
+            outcome.map_or_else(Outcome::Failed, |_| Outcome::Fetched),
+        ));
+    }
+    Ok(outcomes)
+}
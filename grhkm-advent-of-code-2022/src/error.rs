@@ -0,0 +1,103 @@
+use crate::parser::ParseError;
+use reqwest::StatusCode;
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Http(reqwest::Error),
+    Response { status: StatusCode, msg: String },
+    Parse(String),
+    Input(ParseError),
+    Solver(String),
+    Usage(String),
+    Context { context: String, source: Box<Error> },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    // The HTTP status the server answered with, if that's what went wrong
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Http(e) => e.status(),
+            Error::Response { status, .. } => Some(*status),
+            Error::Context { source, .. } => source.status(),
+            _ => None,
+        }
+    }
+
+    // The malformed input behind this error, if that's what went wrong
+    pub fn parse_error(&self) -> Option<&ParseError> {
+        match self {
+            Error::Input(e) => Some(e),
+            Error::Context { source, .. } => source.parse_error(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Http(e) => write!(f, "{e}"),
+            Error::Response { status, msg } => write!(f, "{msg} ({status})"),
+            Error::Input(e) => write!(f, "{e}"),
+            Error::Parse(msg) | Error::Solver(msg) | Error::Usage(msg) => write!(f, "{msg}"),
+            Error::Context { context, source } => write!(f, "{context}: {source}"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Http(e) => Some(e),
+            Error::Input(e) => Some(e),
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Input(e)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Error {
+        Error::Http(e)
+    }
+}
+
+// Says what we were doing when an error happened, e.g. which file we were reading
+pub trait Context<T> {
+    fn context<C: Into<String>>(self, context: C) -> Result<T>;
+
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for std::result::Result<T, E> {
+    fn context<C: Into<String>>(self, context: C) -> Result<T> {
+        self.with_context(|| context)
+    }
+
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.map_err(|e| Error::Context {
+            context: f().into(),
+            source: Box::new(e.into()),
+        })
+    }
+}
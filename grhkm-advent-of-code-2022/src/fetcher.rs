@@ -1,114 +1,228 @@
-use regex::Regex;
-use reqwest::{cookie::Jar, Client, Error, Url};
-use std::fs;
-use std::io::{stdin, stdout, Write};
-use std::process;
-
-fn get_client(url: &str, cookie_file_path: &String) -> Client {
-    let url: Url = url.parse().unwrap();
-
-    let cookie = fs::read_to_string(cookie_file_path)
-        .unwrap_or_else(|_| format!("err: can't read cookie file {cookie_file_path}"));
-
-    let jar = Jar::default();
-    jar.add_cookie_str(&cookie, &url);
-
-    Client::builder()
-        .cookie_provider(jar.into())
-        .build()
-        .expect("err: failed to build reqwest::Client")
+use crate::cache::Cache;
+use crate::config::HttpConfig;
+use crate::consts;
+use crate::error::{Context, Error, Result};
+use crate::input;
+use crate::page;
+use crate::puzzle::Puzzle;
+use crate::session::Session;
+use crate::submission::Verdict;
+use reqwest::header::COOKIE;
+use reqwest::{Client, RequestBuilder, StatusCode, Url};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::runtime::{self, Runtime};
+
+// Day pages gain the answers once a part is solved, so they are only trusted for a while
+const PAGE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+// The site asks for private leaderboards to be fetched no more than every 15 minutes
+const LEADERBOARD_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+// Everything we ask of the server goes through here, sharing one client and runtime.
+// Requests are spaced out and answered from the cache where possible
+pub struct Fetcher {
+    base_url: String,
+    session: Session,
+    client: Client,
+    runtime: Runtime,
+    interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+    cache: Option<Cache>,
+    // Set when the user agent leaves out who to contact, to nag about it once
+    missing_contact: bool,
+    warned: Once,
 }
 
-#[tokio::main]
-pub async fn fetch(day: usize, year: usize, cookie_file_path: &String) -> Result<String, Error> {
-    println!("Downloading from server...");
-
-    let url = &format!("https://adventofcode.com/{year}/day/{day}/input");
-    let client = get_client(url, cookie_file_path);
-
-    let fetch_err = format!("err: Fetching {url} failed");
-    let response = client.get(url).send().await.expect(&fetch_err);
-
-    let status = response.status();
-    if !status.is_success() {
-        let status_err = format!(
-            "err: Fetching {url} failed with status code: {status}\nresponse: {response:#?}",
-        );
-        panic!("{}", &status_err);
+// Turns a failed response into an error, explained by the first line of the page
+fn check_status(status: StatusCode, body: &str) -> Result<()> {
+    if status.is_success() {
+        return Ok(());
     }
-
-    // println!("{:?}", response);
-    println!("status: {}", response.status());
-
-    let body = response
-        .text()
-        .await
-        .expect("err: Extracting .text() failed")
-        .trim_end()
-        .to_string();
-
-    println!("Downloaded day_{day:02}.in from server");
-    Ok(body)
+    let text = page::text(body);
+    let msg = text.lines().map(str::trim).find(|line| !line.is_empty());
+    Err(Error::Response {
+        status,
+        msg: msg.unwrap_or("Request failed").to_string(),
+    })
 }
 
-#[tokio::main]
-pub async fn submit(
-    day: usize,
-    answer: String,
-    level: usize,
-    year: usize,
-    cookie_file_path: &String,
-) {
-    if level != 1 && level != 2 {
-        println!("err: level = {level} is not 1 or 2!");
-        process::exit(1);
+impl Fetcher {
+    pub fn new(base_url: &str, session: Session, http: &HttpConfig) -> Result<Fetcher> {
+        let base_url = base_url.trim_end_matches('/');
+        base_url
+            .parse::<Url>()
+            .map_err(|e| Error::Parse(format!("Invalid URL {base_url:?}: {e}")))?;
+
+        let mut client = Client::builder().user_agent(&http.user_agent);
+        if let Some(timeout) = http.timeout {
+            client = client.timeout(timeout);
+        }
+        let runtime = runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("Starting HTTP runtime")?;
+
+        Ok(Fetcher {
+            base_url: base_url.to_string(),
+            session,
+            client: client.build().context("Building HTTP client")?,
+            runtime,
+            interval: http.interval,
+            last_request: Mutex::new(None),
+            cache: http.cache_dir.clone().map(Cache::new),
+            missing_contact: !http.has_contact(),
+            warned: Once::new(),
+        })
     }
 
-    // confirm from user
-    print!("Submit day {day} level {level} with answer {answer} [y/N]? ");
-    let _ = stdout().flush();
-
-    let mut input = "".to_string();
-    let _ = stdin().read_line(&mut input);
+    // Waits out the rest of the interval since the previous request
+    fn throttle(&self) {
+        let mut last = self.last_request.lock().unwrap();
+        if let Some(wait) = last.and_then(|last| self.interval.checked_sub(last.elapsed())) {
+            thread::sleep(wait);
+        }
+        *last = Some(Instant::now());
+    }
 
-    if !input.to_uppercase().starts_with('Y') {
-        println!("Stopping");
-        return;
+    fn send(&self, request: RequestBuilder) -> Result<(StatusCode, String)> {
+        let request = request.header(COOKIE, self.session.cookie()?);
+        if self.missing_contact {
+            self.warned.call_once(|| {
+                eprintln!(
+                    "warn: Set http.user-agent in {} to something with your contact details, \
+                     such as \"{} (you@example.com)\"",
+                    consts::CONFIG_FILE,
+                    consts::USER_AGENT
+                )
+            });
+        }
+        self.throttle();
+        self.runtime.block_on(async {
+            let response = request.send().await?;
+            let status = response.status();
+            Ok((status, response.text().await?))
+        })
     }
 
-    println!("Submitting to server...");
+    // Answers from the cache if it has a recent enough copy, otherwise downloads it and
+    // caches it if `check` accepts it
+    fn get<F>(&self, path: &str, max_age: Option<Duration>, check: F) -> Result<String>
+    where
+        F: FnOnce(StatusCode, &str) -> Result<()>,
+    {
+        if let Some(body) = self.cache.as_ref().and_then(|c| c.get(path, max_age)) {
+            eprintln!("Using cached {path}");
+            return Ok(body);
+        }
+
+        let url = format!("{}{path}", self.base_url);
+        eprintln!("Downloading {url}...");
+        let (status, body) = self.send(self.client.get(&url))?;
+        check(status, &body)?;
+
+        if let Some(cache) = &self.cache {
+            cache.put(path, &body)?;
+        }
+        Ok(body)
+    }
 
-    let url = &format!("https://adventofcode.com/{year}/day/{day}/answer");
-    let client = get_client(url, cookie_file_path);
+    pub fn fetch(&self, puzzle: Puzzle) -> Result<String> {
+        let Puzzle { year, day } = puzzle;
+        let body = self
+            .get(&format!("/{year}/day/{day}/input"), None, input::validate)
+            .with_context(|| format!("Downloading {puzzle} input"))?;
 
-    let params = [("answer", answer), ("level", level.to_string())];
-    let response = client.post(url).form(&params).send().await;
+        eprintln!("Downloaded {puzzle} input");
+        Ok(input::normalize(&body))
+    }
 
-    let response_body = if response.is_err() {
-        println!("err: Submitting to {url} with params {params:?} failed");
-        process::exit(1);
-    } else {
-        response.unwrap()
-    };
+    // The puzzle description, which also shows the answers once the day is solved
+    pub fn fetch_page(&self, puzzle: Puzzle) -> Result<String> {
+        let Puzzle { year, day } = puzzle;
+        self.get(
+            &format!("/{year}/day/{day}"),
+            Some(PAGE_MAX_AGE),
+            check_status,
+        )
+        .with_context(|| format!("Downloading {puzzle} page"))
+    }
 
-    // println!("{:?}", response_body);
-    println!("status: {}", response_body.status());
+    pub fn submit(&self, puzzle: Puzzle, answer: &str, level: usize) -> Result<Verdict> {
+        if level != 1 && level != 2 {
+            return Err(Error::Usage(format!("level = {level} is not 1 or 2!")));
+        }
+
+        eprintln!("Submitting to server...");
+
+        let Puzzle { year, day } = puzzle;
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let params = [("answer", answer.to_string()), ("level", level.to_string())];
+        let (_, body) = self
+            .send(self.client.post(&url).form(&params))
+            .with_context(|| format!("Submitting {puzzle} level {level}"))?;
+
+        let mut verdict = Verdict::parse(&body);
+
+        // the page now shows the answer, or may do since we last saw it
+        if let (Some(cache), Verdict::Correct | Verdict::WrongLevel) = (&self.cache, &verdict) {
+            cache.remove(&format!("/{year}/day/{day}"));
+        }
+        if verdict == Verdict::WrongLevel {
+            if let Ok(page) = self.fetch_page(puzzle) {
+                verdict = verdict.resolve_level(level, page::answers(&page).len());
+            }
+        }
+
+        eprintln!("Submitted answer for {puzzle}, level {level}!");
+        eprintln!("{verdict}");
+        Ok(verdict)
+    }
 
-    let mut body = response_body
-        .text()
-        .await
-        .expect("err: Extracting .text() failed")
-        .trim()
-        .to_string();
+    // The event calendar, showing which days are unlocked and who is logged in. Never
+    // cached, as it changes with every unlock and star
+    pub fn calendar(&self, year: usize) -> Result<String> {
+        let url = format!("{}/{year}", self.base_url);
+        eprintln!("Downloading {url}...");
+        let (status, body) = self
+            .send(self.client.get(&url))
+            .with_context(|| format!("Downloading the {year} calendar"))?;
+        check_status(status, &body)?;
+        Ok(body)
+    }
 
-    // parse response body, wrapped in <main></main>
-    let re = Regex::new(r"<main>((.|\n)*)</main>").unwrap();
-    if let Some(captures) = re.captures(&body) {
-        body = captures.get(1).unwrap().as_str().to_string();
+    // The JSON of a private leaderboard, which needs a session with access to it
+    pub fn leaderboard(&self, year: usize, id: u64) -> Result<String> {
+        self.get(
+            &format!("/{year}/leaderboard/private/view/{id}.json"),
+            Some(LEADERBOARD_MAX_AGE),
+            |status, body| {
+                check_status(status, body)?;
+                // the login page, if the session can't see this leaderboard
+                if !body.trim_start().starts_with('{') {
+                    return Err(Error::Response {
+                        status,
+                        msg: "Expected the leaderboard JSON, got a page".to_string(),
+                    });
+                }
+                Ok(())
+            },
+        )
+        .with_context(|| format!("Downloading leaderboard {id}"))
     }
 
-    // TODO: parse response and format
+    // The personal times page, with the time and global rank of each of our stars
+    pub fn personal_times(&self, year: usize) -> Result<String> {
+        self.get(
+            &format!("/{year}/leaderboard/self"),
+            Some(PAGE_MAX_AGE),
+            check_status,
+        )
+        .with_context(|| format!("Downloading the {year} personal times"))
+    }
 
-    println!("Submitted answer for Day #{day:02}, level {level}!");
-    println!("Response body:\n{body}");
+    // The name shown in the page header, or None if the session isn't logged in
+    pub fn whoami(&self, year: usize) -> Result<Option<String>> {
+        Ok(page::user(&self.calendar(year)?))
+    }
 }
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub const FORMATS: [&str; 4] = ["table", "json", "csv", "markdown"];
+
+// How results are printed, everything but `Table` is meant for other programs
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" => Ok(Format::Markdown),
+            _ => Err(format!("Unknown format {s:?}, expected one of {FORMATS:?}")),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Table => "table",
+            Format::Json => "json",
+            Format::Csv => "csv",
+            Format::Markdown => "markdown",
+        };
+        write!(f, "{name}")
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn csv(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut res = header.join(",") + "\n";
+    for row in rows {
+        res += &row
+            .iter()
+            .map(|f| csv_field(f))
+            .collect::<Vec<_>>()
+            .join(",");
+        res += "\n";
+    }
+    res
+}
+
+fn markdown_field(field: &str) -> String {
+    field.trim().replace('|', "\\|").replace('\n', "<br>")
+}
+
+pub fn markdown(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut res = format!("| {} |\n", header.join(" | "));
+    res += &format!("|{}\n", " --- |".repeat(header.len()));
+    for row in rows {
+        let row = row.iter().map(|f| markdown_field(f)).collect::<Vec<_>>();
+        res += &format!("| {} |\n", row.join(" | "));
+    }
+    res
+}
@@ -0,0 +1,227 @@
+use crate::puzzle::Puzzle;
+use crate::submission::{Hint, Verdict};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub puzzle: Puzzle,
+    pub level: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved { answer: String },
+    KnownWrong,
+    TooHigh { bound: i128 },
+    TooLow { bound: i128 },
+}
+
+impl Rejection {
+    // Follows on from the `Verdict` exit codes
+    pub fn exit_code(&self) -> i32 {
+        8
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::AlreadySolved { answer } => write!(f, "{answer} was already accepted"),
+            Rejection::KnownWrong => write!(f, "it was already rejected by the server"),
+            Rejection::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Rejection::TooLow { bound } => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+// Every submitted answer, stored as one tab separated line per submission
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<History> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut records = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let record = decode_record(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{path:?}:{}: malformed history record {line:?}", i + 1),
+                )
+            })?;
+            records.push(record);
+        }
+
+        Ok(History { path, records })
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn push(&mut self, record: Record) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", encode_record(&record))?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    // Checks `answer` against everything the server has told us about this part so far
+    pub fn check(&self, puzzle: Puzzle, level: usize, answer: &str) -> Result<(), Rejection> {
+        let records = self
+            .records
+            .iter()
+            .filter(|r| r.puzzle == puzzle && r.level == level);
+
+        let mut too_high: Option<i128> = None;
+        let mut too_low: Option<i128> = None;
+        for record in records {
+            match &record.verdict {
+                Verdict::Correct => {
+                    return Err(Rejection::AlreadySolved {
+                        answer: record.answer.clone(),
+                    })
+                }
+                Verdict::Wrong { hint } => {
+                    if record.answer == answer {
+                        return Err(Rejection::KnownWrong);
+                    }
+                    let Ok(value) = record.answer.parse::<i128>() else {
+                        continue;
+                    };
+                    match hint {
+                        Some(Hint::TooHigh) => {
+                            too_high = Some(too_high.map_or(value, |v| v.min(value)))
+                        }
+                        Some(Hint::TooLow) => {
+                            too_low = Some(too_low.map_or(value, |v| v.max(value)))
+                        }
+                        None => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(bound) = too_high.filter(|&bound| value >= bound) {
+                return Err(Rejection::TooHigh { bound });
+            }
+            if let Some(bound) = too_low.filter(|&bound| value <= bound) {
+                return Err(Rejection::TooLow { bound });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(s: &str) -> String {
+    let mut res = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => res.push('\t'),
+            Some('n') => res.push('\n'),
+            Some(c) => res.push(c),
+            None => res.push('\\'),
+        }
+    }
+    res
+}
+
+fn encode_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => "correct".to_string(),
+        Verdict::Wrong { hint: None } => "wrong".to_string(),
+        Verdict::Wrong {
+            hint: Some(Hint::TooHigh),
+        } => "too-high".to_string(),
+        Verdict::Wrong {
+            hint: Some(Hint::TooLow),
+        } => "too-low".to_string(),
+        Verdict::RateLimited { wait } => format!("rate-limited:{}", wait.as_secs()),
+        Verdict::AlreadySolved => "already-solved".to_string(),
+        Verdict::WrongLevel => "wrong-level".to_string(),
+        Verdict::Unknown(text) => format!("unknown:{}", escape(text)),
+    }
+}
+
+fn decode_verdict(s: &str) -> Option<Verdict> {
+    let verdict = match s.split_once(':').unwrap_or((s, "")) {
+        ("correct", _) => Verdict::Correct,
+        ("wrong", _) => Verdict::Wrong { hint: None },
+        ("too-high", _) => Verdict::Wrong {
+            hint: Some(Hint::TooHigh),
+        },
+        ("too-low", _) => Verdict::Wrong {
+            hint: Some(Hint::TooLow),
+        },
+        ("rate-limited", wait) => Verdict::RateLimited {
+            wait: Duration::from_secs(wait.parse().ok()?),
+        },
+        ("already-solved", _) => Verdict::AlreadySolved,
+        ("wrong-level", _) => Verdict::WrongLevel,
+        ("unknown", text) => Verdict::Unknown(unescape(text)),
+        _ => return None,
+    };
+    Some(verdict)
+}
+
+fn encode_record(record: &Record) -> String {
+    let Record {
+        puzzle,
+        level,
+        answer,
+        verdict,
+    } = record;
+    format!(
+        "{}\t{}\t{level}\t{}\t{}",
+        puzzle.year,
+        puzzle.day,
+        escape(answer),
+        encode_verdict(verdict)
+    )
+}
+
+fn decode_record(line: &str) -> Option<Record> {
+    match line.split('\t').collect::<Vec<_>>()[..] {
+        [year, day, level, answer, verdict] => Some(Record {
+            puzzle: Puzzle::new(year.parse().ok()?, day.parse().ok()?),
+            level: level.parse().ok()?,
+            answer: unescape(answer),
+            verdict: decode_verdict(verdict)?,
+        }),
+        _ => None,
+    }
+}
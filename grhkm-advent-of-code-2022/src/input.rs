@@ -0,0 +1,42 @@
+use crate::error::{Error, Result};
+use crate::page;
+use reqwest::StatusCode;
+
+// What the server says instead of sending an input
+const ERROR_PAGES: [&str; 2] = [
+    "Please log in",
+    "Please don't repeatedly request this endpoint before it unlocks",
+];
+
+// Inputs saved on another platform or by hand parse the same as downloaded ones
+pub fn normalize(contents: &str) -> String {
+    contents.replace("\r\n", "\n").trim_end().to_string()
+}
+
+// The explanation if `body` is an error page rather than an input. Inputs can
+// start with `<` (day 17), so only a whole HTML document counts as a page
+pub fn error_page(body: &str) -> Option<String> {
+    let text = body.trim_start();
+    if let Some(line) = ERROR_PAGES
+        .iter()
+        .find_map(|msg| text.lines().find(|line| line.contains(msg)))
+    {
+        return Some(page::text(line).trim().to_string());
+    }
+
+    let start = text.get(..14).unwrap_or(text).to_lowercase();
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        return Some("Expected a puzzle input, got an HTML page".to_string());
+    }
+    None
+}
+
+// Checks a downloaded input before it gets cached, where a bad one would stick around
+pub fn validate(status: StatusCode, body: &str) -> Result<()> {
+    let msg = match error_page(body) {
+        Some(msg) => msg,
+        None if !status.is_success() => "The server didn't send an input".to_string(),
+        None => return Ok(()),
+    };
+    Err(Error::Response { status, msg })
+}
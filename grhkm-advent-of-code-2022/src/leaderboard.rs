@@ -0,0 +1,138 @@
+use crate::error::{Error, Result};
+use crate::puzzle::Puzzle;
+use crate::unlock;
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::time::{Duration, UNIX_EPOCH};
+
+// A private leaderboard, as served at `/{year}/leaderboard/private/view/{id}.json`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    #[serde(deserialize_with = "crate::config::from_str")]
+    pub event: usize,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    // Missing for anonymous users
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: usize,
+    pub last_star_ts: u64,
+    // The stars earned, by day and then part
+    pub completion_day_level: BTreeMap<usize, BTreeMap<usize, Star>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    // Named the way the site shows anonymous users
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    // How long after `puzzle` unlocked each of its stars was earned
+    pub fn star_times(&self, puzzle: Puzzle) -> Vec<Duration> {
+        let unlock_time = unlock::unlock_time(puzzle);
+        self.completion_day_level
+            .get(&puzzle.day)
+            .into_iter()
+            .flat_map(|parts| parts.values())
+            .map(|star| {
+                let time = UNIX_EPOCH + Duration::from_secs(star.get_star_ts);
+                time.duration_since(unlock_time).unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn day_stars(&self, day: usize) -> usize {
+        self.completion_day_level
+            .get(&day)
+            .map_or(0, |parts| parts.len())
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Leaderboard> {
+        serde_json::from_str(json)
+            .map_err(|e| Error::Parse(format!("Failed to parse leaderboard: {e}")))
+    }
+
+    // Highest local score first, ties going to whoever got there first
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|m| {
+            (
+                Reverse(m.local_score),
+                Reverse(m.stars),
+                m.last_star_ts,
+                m.id,
+            )
+        });
+        members
+    }
+
+    // The ranking with a column of stars for each day, `*` for both parts and `+` for
+    // the first, followed by how long each member took for their stars
+    pub fn format(&self) -> String {
+        let members = self.ranked();
+        let days = members
+            .iter()
+            .flat_map(|m| m.completion_day_level.keys())
+            .max()
+            .copied()
+            .unwrap_or(0);
+        let rank_width = members.len().to_string().len();
+        let score_width = members
+            .iter()
+            .map(|m| m.local_score.to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        let margin = " ".repeat(rank_width + score_width + 4);
+        let digit = |n: usize| char::from_digit(n as u32, 10).unwrap();
+        let tens = (1..=days)
+            .map(|day| if day >= 10 { digit(day / 10) } else { ' ' })
+            .collect::<String>();
+        let units = (1..=days).map(|day| digit(day % 10)).collect::<String>();
+
+        let mut out = String::new();
+        if !tens.trim().is_empty() {
+            out += &format!("{margin}{}\n", tens.trim_end());
+        }
+        out += &format!("{margin}{units}\n");
+        for (rank, member) in (1..).zip(&members) {
+            let stars = (1..=days)
+                .map(|day| ['.', '+', '*'][member.day_stars(day).min(2)])
+                .collect::<String>();
+            out += &format!(
+                "{rank:>rank_width$}) {:>score_width$}  {stars}  {}\n",
+                member.local_score,
+                member.display_name()
+            );
+        }
+
+        out += "\nStar times after unlock:\n";
+        for member in members.iter().filter(|m| m.stars > 0) {
+            out += &format!("{}\n", member.display_name());
+            for &day in member.completion_day_level.keys() {
+                let times = member
+                    .star_times(Puzzle::new(self.event, day))
+                    .into_iter()
+                    .map(|time| format!(" {:>11}", unlock::format_countdown(time)))
+                    .collect::<String>();
+                out += &format!("  Day {day:>2}:{times}\n");
+            }
+        }
+        out
+    }
+}
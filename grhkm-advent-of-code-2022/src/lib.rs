@@ -1,11 +1,26 @@
 #![feature(trait_alias)]
-#![feature(downcast_unchecked)]
-#![feature(box_into_inner)]
-#![feature(let_chains)]
-#![feature(map_try_insert)]
 
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod config;
 pub mod consts;
+pub mod download;
+pub mod error;
 pub mod fetcher;
+pub mod format;
+pub mod history;
+pub mod input;
+pub mod leaderboard;
+pub mod page;
+pub mod parser;
+pub mod puzzle;
+pub mod runner;
+pub mod scaffold;
+pub mod session;
 pub mod solutions;
 pub mod solver;
+pub mod stats;
+pub mod submission;
+pub mod unlock;
 pub mod utils;
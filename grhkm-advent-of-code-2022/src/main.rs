@@ -1,71 +1,686 @@
-#![feature(trait_alias)]
-#![feature(downcast_unchecked)]
-#![feature(box_into_inner)]
-#![feature(let_chains)]
-#![feature(map_try_insert)]
-
+use advent_of_code_2022::answers::KnownAnswers;
+use advent_of_code_2022::bench::Benchmark;
+use advent_of_code_2022::config::Config;
+use advent_of_code_2022::download::{self, Outcome};
+use advent_of_code_2022::error::{Context, Error, Result};
+use advent_of_code_2022::fetcher::Fetcher;
+use advent_of_code_2022::format::Format;
+use advent_of_code_2022::history::{History, Record};
+use advent_of_code_2022::leaderboard::Leaderboard;
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::runner::{self, Report, Status};
+use advent_of_code_2022::session::Session;
+use advent_of_code_2022::solver::{Answer, Output};
+use advent_of_code_2022::unlock::{self, SystemClock};
 use advent_of_code_2022::*;
-use clap::{arg, command, ArgAction};
+use clap::{arg, command, ArgAction, ArgGroup, ArgMatches, Command};
 use std::fs;
-use std::path::Path;
+use std::io::{stderr, stdin, Write};
+use std::path::{Path, PathBuf};
 use std::process;
+use std::slice;
+use std::str::FromStr;
+use std::time::Duration;
 
 struct Data {
-    cookie_file_path: String,
+    config: Config,
+    fetcher: Fetcher,
     submit1: bool,
     submit2: bool,
+    confirm: bool,
+    part: Option<usize>,
+    timeout: Option<Duration>,
+    parallel: bool,
+    format: Format,
 }
 
-// TODO: Wrap this into a struct
-// TODO: Use some error handling module instead of `panic` everywhere
-fn run_day_solution(day: usize, input_file_str: Option<&String>, data: &Data) {
-    if day > solver::DAYS {
-        process::exit(1)
+fn parse_arg<T: FromStr>(matches: &ArgMatches, name: &str) -> Result<Option<T>> {
+    matches
+        .get_one::<String>(name)
+        .map(|value| {
+            value
+                .parse::<T>()
+                .map_err(|_| Error::Parse(format!("Failed to parse {name} {value:?}")))
+        })
+        .transpose()
+}
+
+fn parse_timeout(matches: &ArgMatches) -> Result<Option<Duration>> {
+    parse_arg::<f64>(matches, "timeout")?
+        .map(|secs| {
+            Duration::try_from_secs_f64(secs)
+                .ok()
+                .filter(|timeout| !timeout.is_zero())
+                .ok_or_else(|| {
+                    Error::Usage(format!(
+                        "Timeout must be a positive number of seconds, not {secs}"
+                    ))
+                })
+        })
+        .transpose()
+}
+
+fn parse_base_url(matches: &ArgMatches) -> String {
+    matches
+        .get_one::<String>("base-url")
+        .map_or(consts::BASE_URL, |url| url.trim_end_matches('/'))
+        .to_string()
+}
+
+// The session is only read once a request needs it
+fn parse_session(matches: &ArgMatches, config: &Config) -> Session {
+    let cookie_file = matches.get_one::<String>("cookie").map(PathBuf::from);
+    Session::from_env(cookie_file, config.session_file.clone())
+}
+
+fn parse_fetcher(matches: &ArgMatches, config: &Config) -> Result<Fetcher> {
+    let session = parse_session(matches, config);
+    Fetcher::new(&parse_base_url(matches), session, &config.http())
+}
+
+fn parse_year(matches: &ArgMatches, config: &Config) -> Result<usize> {
+    Ok(parse_arg(matches, "year")?.unwrap_or(config.year))
+}
+
+// The answers file given on the command line, or the one in the input directory
+fn answers_path(matches: &ArgMatches, config: &Config) -> PathBuf {
+    matches
+        .get_one::<String>("answers")
+        .map_or_else(|| config.answers_file(), PathBuf::from)
+}
+
+fn load_history(config: &Config) -> Result<History> {
+    History::load(config.history_file()).context("Loading answer history")
+}
+
+fn load_answers(path: &Path) -> Result<KnownAnswers> {
+    KnownAnswers::load(path).with_context(|| format!("Loading known answers from {path:?}"))
+}
+
+fn submit_answer(puzzle: Puzzle, level: usize, answer: String, data: &Data) -> Result<i32> {
+    let mut history = load_history(&data.config)?;
+    if let Err(rejection) = history.check(puzzle, level, &answer) {
+        eprintln!("Not submitting {answer} for {puzzle} level {level}, {rejection}");
+        return Ok(rejection.exit_code());
     }
 
-    let input_file_str = if let Some(input_file_str) = input_file_str {
-        input_file_str.clone()
-    } else {
-        format!("./input/day_{day:02}.in")
+    // confirm from user
+    if data.confirm {
+        eprint!("Submit {puzzle} level {level} with answer {answer} [y/N]? ");
+        let _ = stderr().flush();
+
+        let mut input = "".to_string();
+        let _ = stdin().read_line(&mut input);
+
+        if !input.to_uppercase().starts_with('Y') {
+            eprintln!("Stopping");
+            return Ok(0);
+        }
+    }
+
+    let verdict = data.fetcher.submit(puzzle, &answer, level)?;
+    let code = verdict.exit_code();
+
+    let record = Record {
+        puzzle,
+        level,
+        answer,
+        verdict,
     };
-    let input_file_path = Path::new(&input_file_str);
+    if let Err(e) = history.push(record) {
+        eprintln!("warn: Saving answer to history returned error {e}");
+    }
+    Ok(code)
+}
+
+fn show_history(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year: Option<usize> = parse_arg(matches, "year")?;
+    let day: Option<usize> = parse_arg(matches, "day")?;
+
+    let history = load_history(config)?;
+    let records = history.records().iter().filter(|r| {
+        year.is_none_or(|year| r.puzzle.year == year) && day.is_none_or(|day| r.puzzle.day == day)
+    });
+    for Record {
+        puzzle,
+        level,
+        answer,
+        verdict,
+    } in records
+    {
+        println!("{puzzle} level {level}: {answer} ({verdict})");
+    }
+    Ok(0)
+}
+
+fn input_path(puzzle: Puzzle, input_file_str: Option<&String>, data: &Data) -> PathBuf {
+    if let Some(input_file_str) = input_file_str {
+        PathBuf::from(input_file_str)
+    } else {
+        data.config.input_path(puzzle)
+    }
+}
+
+fn load_input(puzzle: Puzzle, input_file_str: Option<&String>, data: &Data) -> Result<String> {
+    let input_file_path = input_path(puzzle, input_file_str, data);
 
     if !input_file_path.is_file() {
-        let fetcher = fetcher::fetch(day, consts::YEAR, &data.cookie_file_path);
-        let input = match fetcher {
-            Err(e) => panic!("err: Fetcher returned error {e}"),
-            Ok(input) => input,
+        let input = data.fetcher.fetch(puzzle)?;
+        write_file(&input_file_path, &input)?;
+    } else {
+        eprintln!("{input_file_path:?} exists, great!");
+    }
+
+    eprintln!("Using {input_file_path:?} for {puzzle}!");
+
+    let contents = fs::read_to_string(&input_file_path)
+        .with_context(|| format!("Reading {input_file_path:?}"))?;
+    // cached by an older version that didn't check what it downloaded
+    if let Some(msg) = input::error_page(&contents) {
+        return Err(Error::Usage(format!(
+            "{input_file_path:?} holds an error page instead of an input ({msg}), delete it to download it again"
+        )));
+    }
+    Ok(input::normalize(&contents))
+}
+
+// Counts down to the unlock and downloads the input the moment it is out
+fn wait_for_input(puzzle: Puzzle, input_file_str: Option<&String>, data: &Data) -> Result<()> {
+    let input_file_path = input_path(puzzle, input_file_str, data);
+    if input_file_path.is_file() {
+        return Ok(());
+    }
+
+    let clock = SystemClock;
+    let mut waited = false;
+    unlock::wait(puzzle, &clock, |left| {
+        eprint!("\r{puzzle} unlocks in {} ", unlock::format_countdown(left));
+        let _ = stderr().flush();
+        waited = true;
+    });
+    if waited {
+        eprintln!("\r{puzzle} is unlocked!         ");
+    }
+
+    let input = unlock::retry(&clock, 10, Duration::from_secs(1), || {
+        data.fetcher.fetch(puzzle)
+    })?;
+    write_file(&input_file_path, &input)
+}
+
+// Writes a downloaded file, creating the directories leading up to it
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("Creating {parent:?}"))?;
+    }
+    fs::write(path, contents).with_context(|| format!("Writing to {path:?}"))
+}
+
+// Runs every day with a known answer against our real inputs
+fn verify(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year: Option<usize> = parse_arg(matches, "year")?;
+    let day: Option<usize> = parse_arg(matches, "day")?;
+    let timeout = parse_timeout(matches)?.or(config.timeout);
+
+    let answers = load_answers(&answers_path(matches, config))?;
+    let puzzles = answers
+        .puzzles()
+        .into_iter()
+        .filter(|p| year.is_none_or(|year| p.year == year) && day.is_none_or(|day| p.day == day));
+
+    let mut checked = 0;
+    let mut failed = 0;
+    for puzzle in puzzles {
+        checked += 1;
+        if solver::REGISTRY.get(puzzle).is_none() {
+            println!("{puzzle}: No solution registered");
+            failed += 1;
+            continue;
+        }
+        let input_path = config.input_path(puzzle);
+        let Ok(contents) = fs::read_to_string(&input_path) else {
+            println!("{puzzle}: Missing input {input_path:?}");
+            failed += 1;
+            continue;
+        };
+
+        let contents = input::normalize(&contents);
+        let report = runner::run(puzzle, &contents, answers.part(puzzle), timeout);
+        let Some(output) = report.output else {
+            println!("{puzzle}: {}", report.status);
+            if let Status::InvalidInput(e) = &report.status {
+                println!("{}", e.caret());
+            }
+            failed += 1;
+            continue;
         };
-        if let Err(e) = fs::write(input_file_path, input) {
-            panic!("err: Writing to file returned error {e}");
+        let mismatches = answers.check(puzzle, &output);
+        let unchecked = answers.unchecked(puzzle, &output);
+        if mismatches.is_empty() && unchecked.is_empty() {
+            println!("{puzzle}: Ok");
+        } else if mismatches.is_empty() {
+            let levels = unchecked.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+            println!(
+                "{puzzle}: Ok, level {} is drawn and wasn't compared",
+                levels.join(" and ")
+            );
+        } else {
+            mismatches.iter().for_each(|m| print!("{m}"));
+            failed += 1;
+        }
+    }
+
+    println!("{checked} days checked, {failed} failed");
+    Ok(if failed > 0 { 1 } else { 0 })
+}
+
+// Saves the answers shown on the pages of every solved day
+fn harvest(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    let day: Option<usize> = parse_arg(matches, "day")?;
+    let fetcher = parse_fetcher(matches, config)?;
+
+    let mut answers = load_answers(&answers_path(matches, config))?;
+
+    // the calendar says which days have stars, so only their pages are asked for
+    let calendar = page::calendar(&fetcher.calendar(year)?);
+    let mut code = 0;
+    let days = day.map_or(1..=solver::DAYS, |day| day..=day);
+    for puzzle in days.map(|day| Puzzle::new(year, day)) {
+        match calendar.iter().find(|&&(day, _)| day == puzzle.day) {
+            None => {
+                println!("{puzzle}: Not unlocked yet, skipping");
+                continue;
+            }
+            Some((_, 0)) => {
+                println!("{puzzle}: No stars yet, skipping");
+                continue;
+            }
+            Some(_) => {}
+        }
+
+        let page = match fetcher.fetch_page(puzzle) {
+            Ok(page) => page,
+            Err(e) => {
+                println!("{puzzle}: {e}");
+                code = 1;
+                continue;
+            }
+        };
+        for (level, answer) in (1..).zip(page::answers(&page)) {
+            println!("{puzzle} level {level}: {answer}");
+            answers.insert(puzzle, level, answer);
         }
-    } else {
-        println!("{input_file_path:?} exists, great!");
     }
 
-    println!("Running solution with {input_file_path:?} for Day #{day:02}!");
+    answers.save().context("Saving known answers")?;
+    Ok(code)
+}
 
-    let contents = fs::read_to_string(input_file_path).unwrap();
-    let contents = contents.trim_end();
-    let (part1, part2) = solver::solve(contents, day);
-    println!("Part 1: {part1}, Part 2: {part2}");
+// Saves the example input from the puzzle page and suggests a sample test for it
+fn fetch_samples(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    let day: usize = parse_arg(matches, "day")?.unwrap();
+    let puzzle = Puzzle::new(year, day);
 
-    if data.submit1 {
-        fetcher::submit(day, part1, 1, consts::YEAR, &data.cookie_file_path);
+    let page = parse_fetcher(matches, config)?.fetch_page(puzzle)?;
+    let blocks = page::code_blocks(&page);
+    if matches.get_flag("list") {
+        for (i, block) in blocks.iter().enumerate() {
+            println!("--- block {i} ---\n{}", block.trim_end());
+        }
+        return Ok(0);
     }
 
-    if data.submit2 {
-        fetcher::submit(day, part2, 2, consts::YEAR, &data.cookie_file_path);
+    let index = parse_arg(matches, "block")?.or_else(|| page::example_index(&page));
+    let Some(sample) = index.and_then(|i| blocks.get(i)) else {
+        return Err(Error::Usage(format!(
+            "No such code block, {} found on the page",
+            blocks.len()
+        )));
+    };
+
+    let sample_path = config.sample_path(puzzle);
+    if sample_path.is_file() && !matches.get_flag("force") {
+        return Err(Error::Usage(format!(
+            "{sample_path:?} already exists, pass --force to overwrite it"
+        )));
     }
+    write_file(&sample_path, sample.trim_end())?;
+    println!("Saved block {} to {sample_path:?}", index.unwrap());
+
+    // only candidates, as the emphasised values aren't always the answer, so they are
+    // left for us to check and paste into the sample tests
+    let answers = page::example_answers(&page);
+    if !answers.is_empty() {
+        println!("Candidate sample test for {}:", scaffold::SAMPLE_TESTS);
+        println!("    {}", scaffold::sample_test_entry(day, &answers));
+    }
+    Ok(0)
 }
 
-fn main() {
+// Downloads the inputs of many days at once, skipping the ones already saved
+fn fetch_inputs(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    let days = match matches.get_one::<String>("days") {
+        Some(spec) => download::parse_days(spec)?,
+        None => (1..=solver::DAYS).collect(),
+    };
+
+    let fetcher = parse_fetcher(matches, config)?;
+    let outcomes = download::download(&fetcher, config, year, &days)?;
+
+    let (mut fetched, mut cached, mut unavailable) = (0, 0, 0);
+    for (puzzle, outcome) in &outcomes {
+        println!("{puzzle}: {outcome}");
+        match outcome {
+            Outcome::Fetched => fetched += 1,
+            Outcome::Cached => cached += 1,
+            Outcome::Locked | Outcome::Failed(_) => unavailable += 1,
+        }
+    }
+    println!("{fetched} fetched, {cached} already cached, {unavailable} unavailable");
+
+    let failed = outcomes
+        .iter()
+        .any(|(_, outcome)| matches!(outcome, Outcome::Failed(_)));
+    Ok(if failed { 1 } else { 0 })
+}
+
+// Ranks the members of a private leaderboard
+fn show_leaderboard(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    let id: u64 = parse_arg(matches, "id")?.unwrap();
+
+    let json = parse_fetcher(matches, config)?.leaderboard(year, id)?;
+    let leaderboard = Leaderboard::parse(&json)?;
+    print!("{}", leaderboard.format());
+    Ok(0)
+}
+
+// Our times and ranks for each day, flagging the stars we have no working solution for
+fn show_stats(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    let timeout = parse_timeout(matches)?
+        .or(config.timeout)
+        .unwrap_or(stats::TIMEOUT);
+
+    let fetcher = parse_fetcher(matches, config)?;
+    let calendar = fetcher.calendar(year)?;
+    if page::user(&calendar).is_none() {
+        return Err(Error::Usage(
+            "Not logged in, the session cookie has probably expired".to_string(),
+        ));
+    }
+    let times = fetcher.personal_times(year)?;
+
+    // the sample, which is quick to solve, or our real input for days without one
+    let contents = |puzzle| {
+        [config.sample_path(puzzle), config.input_path(puzzle)]
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .find(|contents| !contents.trim().is_empty())
+            .map(|contents| input::normalize(&contents))
+    };
+    let stats = stats::collect(year, &calendar, &times, contents, Some(timeout));
+    print!("{}", stats::format(&stats));
+    Ok(0)
+}
+
+// Checks that the session is still logged in, before a long run needs it
+fn whoami(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    match parse_fetcher(matches, config)?.whoami(year)? {
+        Some(user) => {
+            println!("Logged in as {user}");
+            Ok(0)
+        }
+        None => {
+            println!("Not logged in, the session cookie has probably expired");
+            Ok(1)
+        }
+    }
+}
+
+// Generates and registers the module for a new day, run from the crate root
+fn new_day(matches: &ArgMatches, config: &Config) -> Result<i32> {
+    let year = parse_year(matches, config)?;
+    let day: usize = parse_arg(matches, "day")?.unwrap();
+    if !(1..=solver::DAYS).contains(&day) {
+        return Err(Error::Usage(format!("Day {day} is out of range")));
+    }
+
+    let puzzle = Puzzle::new(year, day);
+    let changed = scaffold::new_day(Path::new("."), puzzle, config)
+        .with_context(|| format!("Scaffolding {puzzle}"))?;
+    println!("Scaffolded {puzzle}:");
+    for path in changed {
+        println!("  {}", path.display());
+    }
+    Ok(0)
+}
+
+fn print_output(puzzle: Puzzle, output: &Output) {
+    println!("{puzzle} parse time: {:?}", output.parse);
+    for (level, answer) in [(1, &output.part1), (2, &output.part2)] {
+        if let Some(Answer { answer, time }) = answer {
+            println!("Part {level}: {answer} ({time:?})");
+        }
+    }
+}
+
+fn print_report(report: &Report) {
+    match &report.output {
+        Some(output) => print_output(report.puzzle, output),
+        None => println!("{}: {}", report.puzzle, report.status),
+    }
+}
+
+// Runs every day of the year, carrying on past the ones that fail
+fn run_all(year: usize, input_file_str: Option<&String>, data: &Data) -> Result<i32> {
+    let inputs = solver::REGISTRY
+        .days(year)
+        .filter(|&day| day >= 1)
+        .map(|day| Puzzle::new(year, day))
+        .map(|puzzle| (puzzle, load_input(puzzle, input_file_str, data)));
+    let reports = runner::run_all(inputs, data.part, data.timeout, data.parallel, |report| {
+        if data.format == Format::Table {
+            print_report(report);
+        }
+    });
+
+    if data.format == Format::Table {
+        println!();
+    }
+    print!("{}", runner::format(&reports, data.format));
+    let failed = reports.iter().any(|r| r.status.is_failure());
+    Ok(if failed { 1 } else { 0 })
+}
+
+// TODO: Wrap this into a struct
+fn run_day_solution(puzzle: Puzzle, input_file_str: Option<&String>, data: &Data) -> Result<i32> {
+    solver::REGISTRY.solver(puzzle)?;
+
+    let contents = load_input(puzzle, input_file_str, data)?;
+    let report = runner::run(puzzle, &contents, data.part, data.timeout);
+    if data.format != Format::Table {
+        print!("{}", runner::format(slice::from_ref(&report), data.format));
+    }
+    let Some(output) = report.output else {
+        // the structured formats already include the status
+        if data.format != Format::Table {
+            return Ok(1);
+        }
+        if let Status::InvalidInput(e) = report.status {
+            return Err(Error::Input(e)).with_context(|| format!("Parsing {puzzle} input"));
+        }
+        return Err(Error::Solver(format!("{puzzle} {}", report.status)));
+    };
+    if data.format == Format::Table {
+        print_output(puzzle, &output);
+    }
+
+    // report the first submission that wasn't accepted
+    let mut codes = Vec::new();
+    for (level, answer, submit) in [
+        (1, output.part1, data.submit1),
+        (2, output.part2, data.submit2),
+    ] {
+        if let Some(Answer { answer, .. }) = answer.filter(|_| submit) {
+            codes.push(submit_answer(puzzle, level, answer, data)?);
+        }
+    }
+
+    Ok(codes.into_iter().find(|&code| code != 0).unwrap_or(0))
+}
+
+fn cli() -> Command {
     // TODO: Rewrite submit arguments as comma separated (-s 1,2)
-    // TODO: Make cookie optional, only required when downloading input
-    let matches = command!()
+    command!()
+        .subcommand_negates_reqs(true)
+        .subcommand(
+            Command::new("verify")
+                .about("Check the solutions against the known answers for our inputs")
+                .arg(arg!(-y --year <YEAR> "Only check this year").required(false))
+                .arg(arg!(-d --day <DAY> "Only check this day").required(false))
+                .arg(arg!(-a --answers <FILE> "The known answers file to use").required(false))
+                .arg(
+                    arg!(-t --timeout <SECONDS> "Give up on a solution after this many seconds")
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("harvest")
+                .about("Save the accepted answers from the pages of solved days")
+                .arg(arg!(-y --year <YEAR> "The event year of the puzzles").required(false))
+                .arg(arg!(-d --day <DAY> "Only harvest this day").required(false))
+                .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(false))
+                .arg(arg!(-a --answers <FILE> "The known answers file to use").required(false))
+                .arg(
+                    arg!(--"base-url" <URL> "The Advent of Code server to talk to").required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("fetch-samples")
+                .about("Save the example input from a puzzle page and suggest its sample test")
+                .arg(arg!(-y --year <YEAR> "The event year of the puzzle").required(false))
+                .arg(arg!(-d --day <DAY> "The day of the puzzle").required(true))
+                .arg(
+                    arg!(-c --cookie <COOKIE_FILE> "The cookie file to use if the page isn't cached")
+                        .required(false),
+                )
+                .arg(
+                    arg!(--"base-url" <URL> "The Advent of Code server to talk to")
+                        .required(false),
+                )
+                .arg(arg!(-b --block <N> "Save this code block instead of the example").required(false))
+                .arg(
+                    arg!(-l --list "List the code blocks on the page")
+                        .required(false)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(-f --force "Overwrite an existing sample")
+                        .required(false)
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("fetch")
+                .about("Download the inputs of many days at once")
+                .arg(
+                    arg!(--all "Download every unlocked day")
+                        .required(false)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(arg!(--days <DAYS> "Download these days, e.g. 1-25 or 1,3,5-7").required(false))
+                .group(ArgGroup::new("which").args(["all", "days"]).required(true))
+                .arg(arg!(-y --year <YEAR> "The event year of the puzzles").required(false))
+                .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(false))
+                .arg(
+                    arg!(--"base-url" <URL> "The Advent of Code server to talk to").required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("leaderboard")
+                .about("Show the standings of a private leaderboard")
+                .arg(arg!(<ID> "The id of the leaderboard, from its URL").id("id"))
+                .arg(arg!(-y --year <YEAR> "The event year of the leaderboard").required(false))
+                .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(false))
+                .arg(
+                    arg!(--"base-url" <URL> "The Advent of Code server to talk to").required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Show our times, ranks and stars, and the starred days without a solution")
+                .arg(arg!(-y --year <YEAR> "The event year to show").required(false))
+                .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(false))
+                .arg(
+                    arg!(--"base-url" <URL> "The Advent of Code server to talk to").required(false),
+                )
+                .arg(
+                    arg!(-t --timeout <SECONDS> "Give up on a solution after this many seconds, 10 by default")
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("whoami")
+                .visible_alias("check-session")
+                .about("Check that the session cookie is still logged in")
+                .arg(arg!(-y --year <YEAR> "The event year to check against").required(false))
+                .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(false))
+                .arg(
+                    arg!(--"base-url" <URL> "The Advent of Code server to talk to").required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("new-day")
+                .about("Generate and register the module for a new day")
+                .arg(arg!(<DAY> "The day to add").id("day"))
+                .arg(arg!(-y --year <YEAR> "The event year of the puzzle").required(false)),
+        )
+        .subcommand(
+            Command::new("history")
+                .about("Show the answers submitted so far")
+                .arg(arg!(-y --year <YEAR> "Only show answers for this year").required(false))
+                .arg(arg!(-d --day <DAY> "Only show answers for this day").required(false)),
+        )
         .arg(arg!(-d --day <DAY> "The day to run the solutions for").required(true))
-        .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(true))
+        .arg(arg!(-y --year <YEAR> "The event year of the puzzles").required(false))
+        .arg(arg!(-c --cookie <COOKIE_FILE> "The cookie file to use").required(false))
         .arg(arg!(-i --input <INPUT_FILE> "The input file to use").required(false))
+        .arg(arg!(--"base-url" <URL> "The Advent of Code server to talk to").required(false))
+        .arg(
+            arg!(-p --part <PART> "Only run this part of the solution")
+                .required(false)
+                .value_parser(["1", "2"]),
+        )
+        .arg(
+            arg!(-t --timeout <SECONDS> "Give up on a solution after this many seconds")
+                .required(false),
+        )
+        .arg(
+            arg!(-j --parallel "Solve the days concurrently when running all of them")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--wait "Wait for the day to unlock, then fetch and solve it right away")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(arg!(--bench <RUNS> "Time each solution over this many runs").required(false))
+        .arg(
+            arg!(--format <FORMAT> "The format to report results in")
+                .required(false)
+                .value_parser(format::FORMATS),
+        )
+        .arg(
+            arg!(--yes "Submit without asking for confirmation")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             arg!(--s1 ... "Include argument to submit part 1 of solution")
                 .required(false)
@@ -86,32 +701,120 @@ fn main() {
                 .required(false)
                 .action(ArgAction::SetTrue),
         )
-        .get_matches();
+}
+
+// Runs the command, returning the exit code on success
+fn dispatch(matches: &ArgMatches) -> Result<i32> {
+    let config = Config::load()?;
+    match matches.subcommand() {
+        Some(("history", matches)) => return show_history(matches, &config),
+        Some(("verify", matches)) => return verify(matches, &config),
+        Some(("harvest", matches)) => return harvest(matches, &config),
+        Some(("new-day", matches)) => return new_day(matches, &config),
+        Some(("fetch-samples", matches)) => return fetch_samples(matches, &config),
+        Some(("whoami", matches)) => return whoami(matches, &config),
+        Some(("fetch", matches)) => return fetch_inputs(matches, &config),
+        Some(("leaderboard", matches)) => return show_leaderboard(matches, &config),
+        Some(("stats", matches)) => return show_stats(matches, &config),
+        _ => {}
+    }
 
     let day: String = matches.get_one::<String>("day").unwrap().clone();
-    let cookie_file_path: String = matches.get_one::<String>("cookie").unwrap().clone();
+    let year = parse_year(matches, &config)?;
+    if year < consts::FIRST_YEAR || solver::REGISTRY.days(year).next().is_none() {
+        return Err(Error::Usage(format!(
+            "No solutions for year {year}, available years are {:?}",
+            solver::REGISTRY.years()
+        )));
+    }
     let input_file_path: Option<&String> = matches.get_one("input");
     let submit1 = matches.get_flag("submit1") || matches.get_flag("s1");
     let submit2 = matches.get_flag("submit2") || matches.get_flag("s2");
+    let part: Option<usize> = parse_arg(matches, "part")?;
+    if part.is_some_and(|part| (part == 1 && submit2) || (part == 2 && submit1)) {
+        return Err(Error::Usage(
+            "Can't submit a part that isn't being run".to_string(),
+        ));
+    }
+    let timeout = parse_timeout(matches)?.or(config.timeout);
+    let bench_runs: Option<usize> = parse_arg(matches, "bench")?;
+    let format: Format = parse_arg(matches, "format")?.unwrap_or(config.format);
 
     let data = Data {
-        cookie_file_path,
+        fetcher: parse_fetcher(matches, &config)?,
         submit1,
         submit2,
+        confirm: config.confirm_submit && !matches.get_flag("yes"),
+        part,
+        timeout,
+        parallel: matches.get_flag("parallel"),
+        format,
+        config,
     };
 
+    if let Some(runs) = bench_runs {
+        if runs == 0 {
+            return Err(Error::Usage("--bench needs at least one run".to_string()));
+        }
+
+        let days = if &day == "all" {
+            solver::REGISTRY
+                .days(year)
+                .filter(|&day| day >= 1)
+                .collect()
+        } else {
+            vec![parse_arg(matches, "day")?.unwrap()]
+        };
+
+        let mut benchmarks = Vec::new();
+        for day in days {
+            let puzzle = Puzzle::new(year, day);
+            let solver = solver::REGISTRY.solver(puzzle)?;
+            let benchmark = match load_input(puzzle, input_file_path, &data) {
+                Err(e) => Benchmark::fetch_failed(puzzle, e.to_string()),
+                Ok(contents) => {
+                    eprintln!("Benchmarking {puzzle} over {runs} runs...");
+                    bench::bench(puzzle, solver, &contents, part, runs)
+                }
+            };
+            benchmarks.push(benchmark);
+        }
+
+        print!("{}", bench::format(&benchmarks, format));
+        let failed = benchmarks.iter().any(|b| b.status.is_failure());
+        return Ok(if failed { 1 } else { 0 });
+    }
+
     if &day == "all" {
         if submit1 || submit2 {
-            println!("warn: Submitting solutions is not supported with day = \"all\"");
+            eprintln!("warn: Submitting solutions is not supported with day = \"all\"");
         }
-        for day in 1..=solver::DAYS {
-            run_day_solution(day, input_file_path, &data);
+        if matches.get_flag("wait") {
+            eprintln!("warn: Waiting for an unlock is not supported with day = \"all\"");
         }
+        run_all(year, input_file_path, &data)
     } else {
-        let day = day.parse::<usize>().unwrap_or_else(|_| {
-            println!("err: Failed to parse day {day:?}");
-            process::exit(1)
-        });
-        run_day_solution(day, input_file_path, &data);
+        let day = day
+            .parse::<usize>()
+            .map_err(|_| Error::Parse(format!("Failed to parse day {day:?}")))?;
+        let puzzle = Puzzle::new(year, day);
+        if matches.get_flag("wait") {
+            // no point racing for an input we can't solve
+            solver::REGISTRY.solver(puzzle)?;
+            wait_for_input(puzzle, input_file_path, &data)?;
+        }
+        run_day_solution(puzzle, input_file_path, &data)
     }
 }
+
+fn main() {
+    let matches = cli().get_matches();
+    let code = dispatch(&matches).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        if let Some(e) = e.parse_error() {
+            eprintln!("{}", e.caret());
+        }
+        1
+    });
+    process::exit(code);
+}
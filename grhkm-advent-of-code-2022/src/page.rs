@@ -0,0 +1,99 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref ANSWER: Regex = Regex::new(r"Your puzzle answer was <code>([^<]*)</code>").unwrap();
+    static ref CALENDAR_DAY: Regex = Regex::new(r#"<a [^>]*href="/\d+/day/(\d+)"[^>]*>"#).unwrap();
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref CODE_BLOCK: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref EMPHASIZED: Regex = Regex::new(r"<code><em>([^<]*)</em></code>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref USER: Regex = Regex::new(r#"(?s)<div class="user">(.*?)</div>"#).unwrap();
+}
+
+pub(crate) fn unescape_html(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// The text of some HTML, without its tags
+pub(crate) fn text(html: &str) -> String {
+    unescape_html(&TAG.replace_all(html, ""))
+}
+
+// The accepted answers shown on a day page, in part order. Empty if the day
+// has no stars yet
+pub fn answers(body: &str) -> Vec<String> {
+    ANSWER
+        .captures_iter(body)
+        .map(|captures| unescape_html(&captures[1]))
+        .collect()
+}
+
+// The logged in user from the page header, with their star count. Logged out
+// pages show a login link instead
+pub fn user(body: &str) -> Option<String> {
+    let captures = USER.captures(body)?;
+    let user = text(&captures[1]);
+    Some(user.split_whitespace().collect::<Vec<_>>().join(" ")).filter(|user| !user.is_empty())
+}
+
+// The text of every `<pre><code>` block in the puzzle description
+pub fn code_blocks(body: &str) -> Vec<String> {
+    CODE_BLOCK
+        .captures_iter(body)
+        .map(|captures| text(&captures[1]))
+        .collect()
+}
+
+// Picks the block that is introduced as an example, falling back to the first one
+pub fn example_index(body: &str) -> Option<usize> {
+    let mut prev_end = 0;
+    let mut first = None;
+    for (i, block) in CODE_BLOCK.find_iter(body).enumerate() {
+        first = first.or(Some(i));
+        let before = body[prev_end..block.start()].to_lowercase();
+        if before.contains("example") {
+            return Some(i);
+        }
+        prev_end = block.end();
+    }
+    first
+}
+
+// The days linked from the event calendar, which are the unlocked ones, with the
+// number of stars earned on each
+pub fn calendar(body: &str) -> Vec<(usize, usize)> {
+    CALENDAR_DAY
+        .captures_iter(body)
+        .filter_map(|captures| {
+            let day = captures[1].parse().ok()?;
+            let link = &captures[0];
+            let stars = if link.contains("calendar-verycomplete") {
+                2
+            } else if link.contains("calendar-complete") {
+                1
+            } else {
+                0
+            };
+            Some((day, stars))
+        })
+        .collect()
+}
+
+// The last emphasised value of each part's description, which is usually the
+// answer for the example
+pub fn example_answers(body: &str) -> Vec<String> {
+    ARTICLE
+        .captures_iter(body)
+        .filter_map(|article| {
+            EMPHASIZED
+                .captures_iter(&article[1])
+                .last()
+                .map(|captures| unescape_html(&captures[1]))
+        })
+        .collect()
+}
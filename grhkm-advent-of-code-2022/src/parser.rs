@@ -0,0 +1,75 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+// A malformed input, pointing at the line and column where it went wrong
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub msg: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    // `at` should be a slice of `contents`, such as a line or a token split off from one,
+    // otherwise we fall back to searching for it
+    pub fn new<M: Into<String>>(contents: &str, at: &str, msg: M) -> ParseError {
+        let start = contents.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + at.len() <= contents.len())
+            .or_else(|| contents.find(at))
+            .unwrap_or(0);
+
+        let before = &contents[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: contents[line_start..]
+                .lines()
+                .next()
+                .unwrap_or("")
+                .to_string(),
+            msg: msg.into(),
+        }
+    }
+
+    // The offending line with a caret under the bad spot
+    pub fn caret(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+        format!("{number} | {}\n{margin} | {indent}^", self.text)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.msg
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parses a slice of `contents`, pointing at it if it's malformed
+pub fn token<T: FromStr>(contents: &str, token: &str) -> ParseResult<T>
+where
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e| ParseError::new(contents, token, format!("Failed to parse {token:?}: {e}")))
+}
+
+// Splits a slice of `contents`, pointing at its end if the delimiter is missing
+pub fn split_once<'a>(contents: &str, s: &'a str, delim: &str) -> ParseResult<(&'a str, &'a str)> {
+    s.split_once(delim)
+        .ok_or_else(|| ParseError::new(contents, &s[s.len()..], format!("Expected {delim:?}")))
+}
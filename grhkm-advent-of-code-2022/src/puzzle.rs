@@ -0,0 +1,19 @@
+use std::fmt;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Puzzle {
+    pub year: usize,
+    pub day: usize,
+}
+
+impl Puzzle {
+    pub const fn new(year: usize, day: usize) -> Puzzle {
+        Puzzle { year, day }
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Year {} Day #{:02}", self.year, self.day)
+    }
+}
@@ -0,0 +1,321 @@
+use crate::error;
+use crate::format::{self, Format};
+use crate::parser::ParseError;
+use crate::puzzle::Puzzle;
+use crate::solver::{Output, REGISTRY};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use serde::Serialize;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// How a single day went when running several of them at once
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    Panicked(String),
+    InvalidInput(ParseError),
+    TimedOut(Duration),
+    FetchFailed(String),
+}
+
+impl Status {
+    // Unfinished days are expected, so only count as failures when they crash
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Ok | Status::Unimplemented)
+    }
+
+    // The details behind the label, for the days that have any
+    pub fn message(&self) -> Option<String> {
+        match self {
+            Status::Ok | Status::Unimplemented => None,
+            Status::Panicked(msg) | Status::FetchFailed(msg) => Some(msg.clone()),
+            Status::InvalidInput(e) => Some(e.to_string()),
+            Status::TimedOut(timeout) => Some(format!("{timeout:?}")),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "Ok",
+            Status::Unimplemented => "Unimplemented",
+            Status::Panicked(_) => "Panicked",
+            Status::InvalidInput(_) => "Invalid input",
+            Status::TimedOut(_) => "Timed out",
+            Status::FetchFailed(_) => "Failed to fetch",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Panicked(msg) | Status::FetchFailed(msg) => {
+                write!(f, "{}: {msg}", self.label())
+            }
+            Status::InvalidInput(e) => write!(f, "{}: {e}", self.label()),
+            Status::TimedOut(timeout) => write!(f, "Timed out after {timeout:?}"),
+            _ => write!(f, "{}", self.label()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub puzzle: Puzzle,
+    pub status: Status,
+    pub output: Option<Output>,
+}
+
+impl Report {
+    pub fn fetch_failed(puzzle: Puzzle, msg: String) -> Report {
+        Report {
+            puzzle,
+            status: Status::FetchFailed(msg),
+            output: None,
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+fn panic_status(msg: String) -> Status {
+    // the messages of `todo!()` and `unimplemented!()`
+    if msg.starts_with("not yet implemented") || msg.starts_with("not implemented") {
+        Status::Unimplemented
+    } else {
+        Status::Panicked(msg)
+    }
+}
+
+// Runs `f` on this thread, turning a panic into the status it deserves
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Status> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| panic_status(panic_message(payload.as_ref())))
+}
+
+// Same as the main thread, some solutions recurse deeply
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+// Runs a solution, turning a panic into a status instead of taking down the whole run.
+// With a timeout the solution runs on a worker thread, which can't be killed, so an
+// overrunning one is left behind to finish in the background
+pub fn run(
+    puzzle: Puzzle,
+    contents: &str,
+    part: Option<usize>,
+    timeout: Option<Duration>,
+) -> Report {
+    let Some(solver) = REGISTRY.get(puzzle) else {
+        return Report {
+            puzzle,
+            status: Status::Unimplemented,
+            output: None,
+        };
+    };
+
+    let result = match timeout {
+        None => catch_panic(|| solver(contents, part)),
+        Some(timeout) => {
+            let contents = contents.to_string();
+            let (tx, rx) = mpsc::channel();
+            thread::Builder::new()
+                .name(puzzle.to_string())
+                .stack_size(STACK_SIZE)
+                .spawn(move || {
+                    let _ = tx.send(catch_panic(|| solver(&contents, part)));
+                })
+                .expect("err: Failed to spawn solver thread");
+
+            match rx.recv_timeout(timeout) {
+                Ok(result) => result,
+                Err(_) => {
+                    return Report {
+                        puzzle,
+                        status: Status::TimedOut(timeout),
+                        output: None,
+                    }
+                }
+            }
+        }
+    };
+
+    match result {
+        Ok(Ok(output)) => Report {
+            puzzle,
+            status: Status::Ok,
+            output: Some(output),
+        },
+        Ok(Err(e)) => Report {
+            puzzle,
+            status: Status::InvalidInput(e),
+            output: None,
+        },
+        Err(status) => Report {
+            puzzle,
+            status,
+            output: None,
+        },
+    }
+}
+
+// Runs each day on its input, or reports why it couldn't be fetched. In parallel the inputs
+// are all read first, to go easy on the server, and the reports only come once every day is
+// done so that they don't interleave. Either way they come in the order of `inputs`
+pub fn run_all(
+    inputs: impl IntoIterator<Item = (Puzzle, error::Result<String>)>,
+    part: Option<usize>,
+    timeout: Option<Duration>,
+    parallel: bool,
+    mut on_report: impl FnMut(&Report),
+) -> Vec<Report> {
+    let run_one = |(puzzle, input): (Puzzle, error::Result<String>)| match input {
+        Err(e) => Report::fetch_failed(puzzle, e.to_string()),
+        Ok(contents) => run(puzzle, &contents, part, timeout),
+    };
+
+    if !parallel {
+        let mut reports = Vec::new();
+        for input in inputs {
+            let report = run_one(input);
+            on_report(&report);
+            reports.push(report);
+        }
+        return reports;
+    }
+
+    let inputs = inputs.into_iter().collect::<Vec<_>>();
+    let pool = ThreadPoolBuilder::new()
+        .stack_size(STACK_SIZE)
+        .build()
+        .expect("err: Failed to start solver threads");
+    let reports = pool.install(|| inputs.into_par_iter().map(run_one).collect::<Vec<_>>());
+    reports.iter().for_each(on_report);
+    reports
+}
+
+fn answer(output: &Option<Output>, level: usize) -> String {
+    let answer = output.as_ref().and_then(|output| match level {
+        1 => output.part1.as_ref(),
+        _ => output.part2.as_ref(),
+    });
+    match answer {
+        None => "-".to_string(),
+        // multi-line answers don't fit in a table
+        Some(a) if a.answer.trim().contains('\n') => "(multi-line)".to_string(),
+        Some(a) => a.answer.clone(),
+    }
+}
+
+// One row per day, followed by the messages of the days that failed
+pub fn format_table(reports: &[Report]) -> String {
+    let mut res = format!(
+        "{:<17} {:<15} {:>20} {:>20} {:>12}\n",
+        "Puzzle", "Status", "Part 1", "Part 2", "Time"
+    );
+    for report in reports {
+        let puzzle = report.puzzle.to_string();
+        res += &format!(
+            "{puzzle:<17} {:<15} {:>20} {:>20} {:>12}\n",
+            report.status.label(),
+            answer(&report.output, 1),
+            answer(&report.output, 2),
+            report
+                .output
+                .as_ref()
+                .map_or("-".to_string(), |output| format!("{:.2?}", output.total()))
+        );
+    }
+    for report in reports.iter().filter(|r| r.status.is_failure()) {
+        res += &format!("{}: {}\n", report.puzzle, report.status);
+        if let Status::InvalidInput(e) = &report.status {
+            res += &format!("{}\n", e.caret());
+        }
+    }
+    res
+}
+
+// A report flattened into plain fields, for the machine-readable formats
+#[derive(Clone, Debug, Serialize)]
+pub struct Row {
+    pub year: usize,
+    pub day: usize,
+    pub status: &'static str,
+    pub message: Option<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_us: Option<u128>,
+    pub part1_us: Option<u128>,
+    pub part2_us: Option<u128>,
+    pub total_us: Option<u128>,
+}
+
+const FIELDS: [&str; 10] = [
+    "year", "day", "status", "message", "part1", "part2", "parse_us", "part1_us", "part2_us",
+    "total_us",
+];
+
+impl Row {
+    pub fn new(report: &Report) -> Row {
+        let output = report.output.as_ref();
+        let part = |level: usize| {
+            output.and_then(|output| match level {
+                1 => output.part1.as_ref(),
+                _ => output.part2.as_ref(),
+            })
+        };
+        Row {
+            year: report.puzzle.year,
+            day: report.puzzle.day,
+            status: report.status.label(),
+            message: report.status.message(),
+            part1: part(1).map(|a| a.answer.clone()),
+            part2: part(2).map(|a| a.answer.clone()),
+            parse_us: output.map(|o| o.parse.as_micros()),
+            part1_us: part(1).map(|a| a.time.as_micros()),
+            part2_us: part(2).map(|a| a.time.as_micros()),
+            total_us: output.map(|o| o.total().as_micros()),
+        }
+    }
+
+    fn fields(&self) -> Vec<String> {
+        let opt = |v: &Option<String>| v.clone().unwrap_or_default();
+        let num = |v: Option<u128>| v.map_or(String::new(), |v| v.to_string());
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.status.to_string(),
+            opt(&self.message),
+            opt(&self.part1),
+            opt(&self.part2),
+            num(self.parse_us),
+            num(self.part1_us),
+            num(self.part2_us),
+            num(self.total_us),
+        ]
+    }
+}
+
+pub fn format(reports: &[Report], format: Format) -> String {
+    let rows = reports.iter().map(Row::new).collect::<Vec<_>>();
+    let fields = || rows.iter().map(Row::fields).collect::<Vec<_>>();
+    match format {
+        Format::Table => format_table(reports),
+        Format::Json => serde_json::to_string_pretty(&rows).unwrap() + "\n",
+        Format::Csv => format::csv(&FIELDS, &fields()),
+        Format::Markdown => format::markdown(&FIELDS, &fields()),
+    }
+}
@@ -0,0 +1,193 @@
+use crate::config::Config;
+use crate::puzzle::Puzzle;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("solutions/solver.rs");
+
+pub const SAMPLE_TESTS: &str = "tests/tests.rs";
+
+fn error(kind: io::ErrorKind, msg: String) -> io::Error {
+    io::Error::new(kind, msg)
+}
+
+// Inserts `entry` into the block opened by the last of `anchors`, keeping the entries sorted by
+// `key`. Lines without a key are attributes, except for the leading ones such as `2022;`
+fn insert_entry(
+    contents: &str,
+    anchors: &[&str],
+    day: usize,
+    key: impl Fn(&str) -> Option<usize>,
+    entry: &str,
+) -> io::Result<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let mut start = 0;
+    for anchor in anchors {
+        start += lines[start..]
+            .iter()
+            .position(|line| line.trim() == *anchor)
+            .ok_or_else(|| error(io::ErrorKind::InvalidData, format!("Missing {anchor:?}")))?;
+        start += 1;
+    }
+
+    let mut index = start;
+    let mut seen_entry = false;
+    for (i, line) in lines.iter().enumerate().skip(start) {
+        if line.trim() == "}" {
+            break;
+        }
+        match key(line.trim()) {
+            Some(other) if other == day => {
+                return Err(error(
+                    io::ErrorKind::AlreadyExists,
+                    format!("Day {day} is already listed"),
+                ))
+            }
+            Some(other) => {
+                seen_entry = true;
+                if other < day {
+                    index = i + 1;
+                }
+            }
+            None if !seen_entry && line.trim().ends_with(';') => index = i + 1,
+            None => {}
+        }
+    }
+    lines.insert(index, entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn update(path: &Path, f: impl FnOnce(&str) -> io::Result<String>) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+    fs::write(path, f(&contents)?)
+}
+
+fn register_year(root: &Path, year: usize) -> io::Result<Vec<PathBuf>> {
+    let year_dir = root.join(format!("src/solutions/y{year}"));
+    fs::create_dir_all(&year_dir)?;
+    let year_mod = year_dir.join("mod.rs");
+    fs::write(
+        &year_mod,
+        format!("use crate::solver::Registry;\n\nregister_solutions! {{\n    {year};\n}}\n"),
+    )?;
+
+    let solutions_mod = root.join("src/solutions/mod.rs");
+    update(&solutions_mod, |contents| {
+        let year_key = |line: &str| {
+            line.strip_prefix("pub mod y")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        };
+        let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+        let index = lines
+            .iter()
+            .rposition(|line| year_key(line).is_some_and(|y: usize| y < year))
+            .map(|i| i + 1)
+            .or_else(|| lines.iter().position(|line| year_key(line).is_some()))
+            .ok_or_else(|| error(io::ErrorKind::InvalidData, "Missing year modules".into()))?;
+        lines.insert(index, format!("pub mod y{year};"));
+        let index = lines
+            .iter()
+            .rposition(|line| line.trim().ends_with("::register(registry);"))
+            .ok_or_else(|| error(io::ErrorKind::InvalidData, "Missing register call".into()))?;
+        lines.insert(index + 1, format!("    y{year}::register(registry);"));
+        Ok(lines.join("\n") + "\n")
+    })?;
+    Ok(vec![year_mod, solutions_mod])
+}
+
+// The line of `solutions_tests!` that checks `day` against its sample, with the parts
+// that have no answer yet left empty
+pub fn sample_test_entry(day: usize, answers: &[String]) -> String {
+    let answer = |i: usize| answers.get(i).map_or("", String::as_str);
+    format!(
+        "test_day_{day:02}: ({day}, ({:?}, {:?})),",
+        answer(0),
+        answer(1)
+    )
+}
+
+fn add_sample_test(root: &Path, puzzle: Puzzle) -> io::Result<PathBuf> {
+    let Puzzle { year, day } = puzzle;
+    let path = root.join(SAMPLE_TESTS);
+    let module = format!("mod y{year} {{");
+    update(&path, |contents| {
+        let mut contents = contents.to_string();
+        if !contents.lines().any(|line| line == module) {
+            contents += &format!(
+                "\n#[cfg(sample_test)]\n{module}\n    use super::common::utils::*;\n    \
+                 use advent_of_code_2022::puzzle::Puzzle;\n    \
+                 use pretty_assertions::assert_eq;\n\n    \
+                 solutions_tests! {{\n        {year};\n    }}\n}}\n"
+            );
+        }
+        let key = |line: &str| {
+            line.strip_prefix("test_day_")?
+                .split(':')
+                .next()?
+                .parse()
+                .ok()
+        };
+        let entry = format!(
+            "        #[ignore = \"pending\"]\n        {}",
+            sample_test_entry(day, &[])
+        );
+        insert_entry(
+            &contents,
+            &[&module, "solutions_tests! {"],
+            day,
+            key,
+            &entry,
+        )
+    })?;
+    Ok(path)
+}
+
+// Generates the module for a new day under the crate at `root` and registers it everywhere, along
+// with an empty sample input and a pending sample test. Returns the files created or changed
+pub fn new_day(root: &Path, puzzle: Puzzle, config: &Config) -> io::Result<Vec<PathBuf>> {
+    let Puzzle { year, day } = puzzle;
+    let year_dir = root.join(format!("src/solutions/y{year}"));
+    let module_path = year_dir.join(format!("day_{day:02}.rs"));
+    if module_path.exists() {
+        return Err(error(
+            io::ErrorKind::AlreadyExists,
+            format!("{module_path:?} already exists"),
+        ));
+    }
+
+    let mut changed = vec![];
+    if !year_dir.join("mod.rs").exists() {
+        changed.extend(register_year(root, year)?);
+    }
+
+    let year_mod = year_dir.join("mod.rs");
+    update(&year_mod, |contents| {
+        let key = |line: &str| line.split(" => ").next()?.parse().ok();
+        let entry = format!("    {day} => day_{day:02}::Day{day:02},");
+        insert_entry(contents, &["register_solutions! {"], day, key, &entry)
+    })?;
+    fs::write(
+        &module_path,
+        TEMPLATE.replace("DayXX", &format!("Day{day:02}")),
+    )?;
+    changed.extend([module_path, year_mod]);
+
+    // only the sample, as an empty input would pass for a downloaded one
+    let sample_path = config.sample_path(puzzle);
+    let sample_path = root.join(sample_path.strip_prefix(".").unwrap_or(&sample_path));
+    if !sample_path.exists() {
+        if let Some(parent) = sample_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&sample_path, "")?;
+        changed.push(sample_path);
+    }
+
+    changed.push(add_sample_test(root, puzzle)?);
+    changed.sort();
+    changed.dedup();
+    Ok(changed)
+}
@@ -0,0 +1,96 @@
+use crate::config;
+use crate::consts;
+use crate::error::{Context, Error, Result};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+// Where to find the session cookie, in order of preference. Nothing is read
+// until a request actually needs it, so cached inputs work without one
+#[derive(Debug, Default)]
+pub struct Session {
+    cookie_file: Option<PathBuf>,
+    env: Option<String>,
+    user_file: Option<PathBuf>,
+    cookie: OnceLock<String>,
+}
+
+impl Session {
+    pub fn new(
+        cookie_file: Option<PathBuf>,
+        env: Option<String>,
+        user_file: Option<PathBuf>,
+    ) -> Session {
+        Session {
+            cookie_file,
+            env,
+            user_file,
+            cookie: OnceLock::new(),
+        }
+    }
+
+    // `--cookie`, then `AOC_SESSION`, then the configured session file or else the
+    // per-user one
+    pub fn from_env(cookie_file: Option<PathBuf>, session_file: Option<PathBuf>) -> Session {
+        let env = env::var(consts::SESSION_ENV)
+            .ok()
+            .filter(|value| !value.trim().is_empty());
+        Session::new(cookie_file, env, session_file.or_else(user_file))
+    }
+
+    // The `session=...` cookie to send, loaded on first use
+    pub fn cookie(&self) -> Result<&str> {
+        if let Some(cookie) = self.cookie.get() {
+            return Ok(cookie);
+        }
+        let cookie = self.load()?;
+        Ok(self.cookie.get_or_init(|| cookie))
+    }
+
+    fn load(&self) -> Result<String> {
+        if let Some(path) = &self.cookie_file {
+            let value = fs::read_to_string(path)
+                .with_context(|| format!("Reading cookie file {path:?}"))?;
+            return Ok(to_cookie(&value));
+        }
+        if let Some(value) = &self.env {
+            return Ok(to_cookie(value));
+        }
+        // unlike --cookie, the user file is optional
+        if let Some(value) = self
+            .user_file
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .filter(|value| !value.trim().is_empty())
+        {
+            return Ok(to_cookie(&value));
+        }
+
+        let user_file = self
+            .user_file
+            .as_ref()
+            .map_or("the user session file".to_string(), |path| {
+                format!("{path:?}")
+            });
+        Err(Error::Usage(format!(
+            "No session cookie, pass --cookie, set {} or save it to {user_file}",
+            consts::SESSION_ENV
+        )))
+    }
+}
+
+// The per-user session file, next to the user config
+pub fn user_file() -> Option<PathBuf> {
+    config::user_dir().map(|dir| dir.join(consts::SESSION_FILE))
+}
+
+// Accepts both the bare token copied from the browser and a whole cookie
+fn to_cookie(value: &str) -> String {
+    let value = value.trim();
+    if value.contains('=') {
+        value.to_string()
+    } else {
+        format!("session={value}")
+    }
+}
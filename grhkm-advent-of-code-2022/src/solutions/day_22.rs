@@ -1,4 +0,0 @@
-pub fn solve(_contents: &str) -> (usize, usize) {
-    // TOOD: Solve day 22
-    todo!();
-}
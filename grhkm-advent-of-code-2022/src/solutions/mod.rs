@@ -1,26 +1,17 @@
-pub mod day_00;
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_19;
-pub mod day_20;
-pub mod day_21;
-pub mod day_22;
-pub mod day_23;
-pub mod day_24;
-pub mod day_25;
+use crate::solver::Registry;
+
+macro_rules! register_solutions {
+    ($year:literal; $($day:literal => $module:ident::$solution:ident,)*) => {
+        $(pub mod $module;)*
+
+        pub fn register(registry: &mut Registry) {
+            $(registry.register::<$module::$solution>($year, $day);)*
+        }
+    };
+}
+
+pub mod y2022;
+
+pub fn register(registry: &mut Registry) {
+    y2022::register(registry);
+}
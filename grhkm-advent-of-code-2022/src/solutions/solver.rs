@@ -1,3 +1,22 @@
-pub fn solve(contents: &str) -> (usize, usize) {
-    (0, 0)
+use crate::parser::ParseResult;
+use crate::solver::Solution;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(_contents: &str) -> ParseResult<Self::Input> {
+        todo!()
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Part1 {
+        todo!()
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        todo!()
+    }
 }
@@ -0,0 +1,27 @@
+use crate::parser::{self, ParseResult};
+use crate::solver::Solution;
+use num_bigint::BigUint;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    // big enough to show answers needn't fit in a machine word
+    type Part2 = BigUint;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .lines()
+            .map(|line| parser::token(contents, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.iter().sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.iter().map(|&x| BigUint::from(x) * 10u32).sum()
+    }
+}
@@ -0,0 +1,44 @@
+use crate::parser::{self, ParseResult};
+use crate::solver::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        // process each player separated by two new lines
+        // keeping track of their running sums
+        let mut vals = Vec::new();
+        for player in contents.split("\n\n") {
+            let mut cur_val = 0;
+            for line in player.split('\n') {
+                let line_val = if line.is_empty() {
+                    0
+                } else {
+                    parser::token::<usize>(contents, line)?
+                };
+                cur_val += line_val;
+                vals.push(cur_val);
+            }
+            // println!("player: {cur_val}");
+        }
+
+        // sort_unstable_by allows custom comparator
+        // which must return an `Ordering`
+        vals.sort_unstable_by(|a, b| a.cmp(b).reverse());
+        Ok(vals)
+    }
+
+    fn part1(vals: &Self::Input) -> Self::Part1 {
+        // the largest running sum is the maximum of the players
+        vals[0]
+    }
+
+    fn part2(vals: &Self::Input) -> Self::Part2 {
+        // now compute sum of maximum 3 calories
+        vals[0] + vals[1] + vals[2]
+    }
+}
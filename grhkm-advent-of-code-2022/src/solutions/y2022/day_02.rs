@@ -0,0 +1,90 @@
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
+
+fn round(move1: usize, move2: usize) -> usize {
+    // returns score based on whether move2 beats move1
+    match [move1, move2] {
+        [1, 1] => 3,
+        [1, 2] => 6,
+        [1, 3] => 0,
+        [2, 1] => 0,
+        [2, 2] => 3,
+        [2, 3] => 6,
+        [3, 1] => 6,
+        [3, 2] => 0,
+        [3, 3] => 3,
+        _ => unreachable!(),
+    }
+}
+
+fn parse_tuples(from: &str) -> ParseResult<Vec<(&str, &str)>> {
+    from.trim()
+        .lines()
+        .map(|x| parser::split_once(from, x, " "))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(usize, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let unexpected = |s| ParseError::new(contents, s, format!("Unexpected move {s:?}"));
+        parse_tuples(contents)?
+            .into_iter()
+            .map(|line| {
+                // convert to numbers
+                let move1 = match line.0 {
+                    "A" => 1,
+                    "B" => 2,
+                    "C" => 3,
+                    s => return Err(unexpected(s)),
+                };
+
+                let move2 = match line.1 {
+                    "X" => 1,
+                    "Y" => 2,
+                    "Z" => 3,
+                    s => return Err(unexpected(s)),
+                };
+
+                Ok((move1, move2))
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> Self::Part1 {
+        rounds
+            .iter()
+            // first add shape selected, next add outcome of the round
+            .map(|&(move1, move2)| move2 + round(move1, move2))
+            .sum()
+    }
+
+    fn part2(rounds: &Self::Input) -> Self::Part2 {
+        let mut part2 = 0;
+
+        for &(move1, target) in rounds {
+            // X, Y, Z are now the outcomes we need
+            let target_round = (target - 1) * 3;
+
+            // we check all moves and see if we get our required results
+            for move2 in 1..4 {
+                if round(move1, move2) == target_round {
+                    // first add shape selected
+                    part2 += move2;
+
+                    // next add outcome of the round
+                    part2 += round(move1, move2);
+
+                    break;
+                }
+            }
+        }
+
+        part2
+    }
+}
@@ -0,0 +1,65 @@
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
+
+fn priority(c: char) -> usize {
+    let c = c as u8;
+    (match c {
+        b'A'..=b'Z' => c - 0x41 + 1,
+        b'a'..=b'z' => c - 0x61 + 1,
+        _ => unreachable!(),
+    }) as usize
+}
+
+fn intersect(s1: Vec<char>, s2: Vec<char>) -> Vec<char> {
+    let mut res = Vec::new();
+    for c in s1 {
+        if s2.contains(&c) {
+            res.push(c);
+        }
+    }
+    res
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .lines()
+            .map(|line| {
+                // only letters have a priority
+                match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    Some((i, c)) => Err(ParseError::new(
+                        contents,
+                        &line[i..],
+                        format!("Unexpected item {c:?}"),
+                    )),
+                    None => Ok(line.chars().collect()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        let mut part1 = 0;
+        for line in lines {
+            let n = line.len();
+            let chars1 = line[0..n / 2].to_vec();
+            let chars2 = line[n / 2..n].to_vec();
+            part1 += priority(intersect(chars1, chars2)[0]);
+        }
+        part1
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        let mut part2 = 0;
+        for group in lines.chunks(3) {
+            part2 += priority(group.iter().cloned().reduce(intersect).expect("")[0]);
+        }
+        part2
+    }
+}
@@ -0,0 +1,68 @@
+use crate::parser::{self, ParseResult};
+use crate::solver::Solution;
+use std::mem;
+
+#[derive(Clone)]
+pub struct Interval {
+    l: usize,
+    r: usize,
+}
+
+fn make_interval(contents: &str, s: &str) -> ParseResult<Interval> {
+    let parts = parser::split_once(contents, s, "-")?;
+    Ok(Interval {
+        l: parser::token(contents, parts.0)?,
+        r: parser::token(contents, parts.1)?,
+    })
+}
+
+fn cover_entire(x: &mut Interval, y: &mut Interval) -> bool {
+    // [1, 4] -> [1, 2], [2, 3] but not [2, 5]
+    if x.l > y.l {
+        mem::swap(x, y);
+    }
+    (x.l == y.l) || (x.r >= y.r)
+}
+
+fn cover_partly(x: &mut Interval, y: &mut Interval) -> bool {
+    // [1, 5] -> [2, 6], [4, 5] but not [6, 7]
+    if x.l > y.l {
+        mem::swap(x, y);
+    }
+    x.r >= y.l
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(Interval, Interval)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .lines()
+            .map(|s| {
+                let line = parser::split_once(contents, s, ",")?;
+                Ok((
+                    make_interval(contents, line.0)?,
+                    make_interval(contents, line.1)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Self::Part1 {
+        pairs
+            .iter()
+            .filter(|(x, y)| cover_entire(&mut x.clone(), &mut y.clone()))
+            .count()
+    }
+
+    fn part2(pairs: &Self::Input) -> Self::Part2 {
+        pairs
+            .iter()
+            .filter(|(x, y)| cover_partly(&mut x.clone(), &mut y.clone()))
+            .count()
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
+use itertools::Itertools;
+
+// Fifo: stack, Filo: queue
+#[derive(PartialEq)]
+enum OperationOrder {
+    Fifo,
+    Filo,
+}
+
+impl fmt::Display for OperationOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            OperationOrder::Fifo => write!(f, "Part 1 (Fifo): "),
+            OperationOrder::Filo => write!(f, "Part 2 (Filo): "),
+        }
+    }
+}
+
+trait Applicable {
+    fn apply<F, T2>(self, f: F) -> T2
+    where
+        F: Fn(Self) -> T2,
+        Self: Sized,
+    {
+        f(self)
+    }
+}
+
+impl<T> Applicable for T {}
+
+pub struct Crates {
+    rows: Vec<Vec<char>>,
+    moves: Vec<(usize, usize, usize)>,
+}
+
+fn parse(contents: &str) -> ParseResult<Crates> {
+    let (diagram, moves) = parser::split_once(contents, contents, "\n\n")?;
+    let mut diagram_lines = diagram.lines().collect::<Vec<_>>();
+    diagram_lines.reverse();
+
+    // parse diagram into Vec<Vec<char>>
+    let Some(labels) = diagram_lines.first() else {
+        return Err(ParseError::new(contents, diagram, "Expected a diagram"));
+    };
+    let cols = labels.split_whitespace().count();
+    let mut rows = vec![Vec::new(); cols];
+
+    for row in &diagram_lines[1..] {
+        let row = row.chars().collect::<Vec<_>>();
+        // println!("{row:?}");
+        for (i, stack) in rows.iter_mut().enumerate() {
+            // editors may strip the trailing spaces of a row
+            let c = row.get(i * 4 + 1).copied().unwrap_or(' ');
+            if c != ' ' {
+                stack.push(c);
+                // print!("{i} -> {c}");
+            }
+        }
+        // println!();
+    }
+    // println!("rows: {rows:?}");
+
+    // parse moves
+    let pw = |x: &str| parser::token::<usize>(contents, x);
+    let moves = moves
+        .lines()
+        .map(|l| {
+            l.split_whitespace()
+                .next_tuple::<(_, _, _, _, _, _)>()
+                .ok_or_else(|| {
+                    ParseError::new(contents, l, "Expected a move like \"move 1 from 2 to 1\"")
+                })?
+                .apply(|(_, x, _, y, _, z)| Ok((pw(x)?, pw(y)?, pw(z)?)))
+        })
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Crates { rows, moves })
+}
+
+fn _solve(crates: &Crates, option: OperationOrder) -> String {
+    let mut rows = crates.rows.clone();
+
+    // process moves, creating temporary queue
+    for &(num, src_idx, dest_idx) in &crates.moves {
+        // pop from [src] stack
+        let src = &mut rows[src_idx - 1];
+        let mut tmp = src[src.len() - num..].to_vec();
+
+        if option == OperationOrder::Fifo {
+            tmp.reverse();
+        }
+
+        src.truncate(src.len() - num);
+        rows[dest_idx - 1].extend(tmp);
+    }
+
+    rows.into_iter().map(|r| r[r.len() - 1]).collect::<String>()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Crates;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        parse(contents)
+    }
+
+    fn part1(crates: &Self::Input) -> Self::Part1 {
+        _solve(crates, OperationOrder::Fifo)
+    }
+
+    fn part2(crates: &Self::Input) -> Self::Part2 {
+        _solve(crates, OperationOrder::Filo)
+    }
+}
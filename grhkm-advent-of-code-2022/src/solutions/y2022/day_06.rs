@@ -1,3 +1,6 @@
+use crate::parser::ParseResult;
+use crate::solver::Solution;
+
 fn first_match(s: &str) -> Option<usize> {
     // return smallest index i such that
     // there exists j such that s[i] == s[j]
@@ -34,8 +37,22 @@ fn _solve(s: &str, k: usize) -> Option<usize> {
     None
 }
 
-pub fn solve(s: &str) -> (usize, usize) {
-    let part1 = _solve(s, 4).expect("err: can't find index for part 1");
-    let part2 = _solve(s, 14).expect("err: can't find index for part 2");
-    (part1, part2)
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(s: &str) -> ParseResult<Self::Input> {
+        Ok(s.to_string())
+    }
+
+    fn part1(s: &Self::Input) -> Self::Part1 {
+        _solve(s, 4).expect("err: can't find index for part 1")
+    }
+
+    fn part2(s: &Self::Input) -> Self::Part2 {
+        _solve(s, 14).expect("err: can't find index for part 2")
+    }
 }
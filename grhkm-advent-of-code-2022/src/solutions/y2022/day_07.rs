@@ -1,3 +1,5 @@
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
 use std::cell::RefCell;
 use std::default::Default;
 use std::fmt::Debug;
@@ -65,7 +67,10 @@ where
     }
 }
 
-fn init<'a>(arena: &'a Arena<Node<'a, usize>>, contents: &'a str) -> &'a Node<'a, usize> {
+fn init<'a>(
+    arena: &'a Arena<Node<'a, usize>>,
+    contents: &'a str,
+) -> ParseResult<&'a Node<'a, usize>> {
     let cur_path: &mut Vec<&'a Node<'a, usize>> = &mut Vec::new();
     cur_path.clear();
     let root = Node::new(None, "/", arena);
@@ -83,14 +88,14 @@ fn init<'a>(arena: &'a Arena<Node<'a, usize>>, contents: &'a str) -> &'a Node<'a
         match cmd {
             ("ls", "") => {
                 for output in &cmd_group[1..] {
-                    let (data, dir) = output.split_once(' ').unwrap();
+                    let (data, dir) = parser::split_once(contents, output, " ")?;
 
                     // assume node is new
                     let cur = cur_path[cur_path.len() - 1];
 
                     let val = match data {
                         "dir" => None,
-                        data => Some(data.parse().unwrap()),
+                        data => Some(parser::token(contents, data)?),
                     };
 
                     match cur.find_name(dir) {
@@ -124,34 +129,50 @@ fn init<'a>(arena: &'a Arena<Node<'a, usize>>, contents: &'a str) -> &'a Node<'a
                 };
                 cur_path.push(node);
             }
-            _ => unreachable!(),
+            _ => {
+                return Err(ParseError::new(
+                    contents,
+                    cmd_group[0],
+                    format!("Unknown command {:?}", cmd_group[0]),
+                ))
+            }
         }
     }
-    root
+    Ok(root)
 }
 
-pub fn solve(contents: &str) -> (usize, usize) {
-    let arena = Arena::new();
+pub struct Day07;
+
+impl Solution for Day07 {
+    // the size of every directory, starting with the root
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    // construct graph
-    let g = init(&arena, contents);
-    let res = g
-        .traverse(&|v| {
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let arena = Arena::new();
+
+        // construct graph
+        let g = init(&arena, contents)?;
+        Ok(g.traverse(&|v| {
             // println!("{:?} -> {:?}", v, v.sum());
             (v.val.is_none(), v.sum())
         })
         .iter()
         .filter(|(is_dir, _)| *is_dir)
         .map(|(_, sum)| *sum)
-        .collect::<Vec<_>>();
+        .collect())
+    }
 
-    let sum: usize = g.sum();
-    let part1: usize = res.iter().filter(|&&s| s <= 100000).sum();
-    let part2: usize = *res
-        .iter()
-        .filter(|&&s| s >= sum - 40000000)
-        .min()
-        .expect("err: no files can be freed");
+    fn part1(res: &Self::Input) -> Self::Part1 {
+        res.iter().filter(|&&s| s <= 100000).sum()
+    }
 
-    (part1, part2)
+    fn part2(res: &Self::Input) -> Self::Part2 {
+        let sum = res[0];
+        *res.iter()
+            .filter(|&&s| s >= sum - 40000000)
+            .min()
+            .expect("err: no files can be freed")
+    }
 }
@@ -0,0 +1,88 @@
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
+use itertools::Itertools;
+
+pub fn parse_board(contents: &str) -> ParseResult<Vec<Vec<usize>>> {
+    let mut res: Vec<Vec<usize>> = Vec::new();
+    for line in contents.lines() {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                    ParseError::new(
+                        contents,
+                        &line[i..],
+                        format!("Expected a height, not {c:?}"),
+                    )
+                })
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        if res.first().is_some_and(|first| first.len() != row.len()) {
+            let msg = format!("Expected {} trees in a row", res[0].len());
+            return Err(ParseError::new(contents, line, msg));
+        }
+        res.push(row)
+    }
+    Ok(res)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    // the heights seen looking out in each direction from every tree
+    type Input = Vec<[Vec<isize>; 4]>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let board: Vec<Vec<usize>> = parse_board(contents)?;
+        let r = board.len();
+        let c = board.first().map_or(0, Vec::len);
+
+        let look_dir = |x: isize, y: isize, dx: isize, dy: isize| {
+            let r = r as isize;
+            let c = c as isize;
+            let mut res = Vec::new();
+            let (mut cx, mut cy) = (x, y);
+            while 0 <= cx && cx < r && 0 <= cy && cy < c {
+                res.push(board[cx as usize][cy as usize] as isize);
+                cx += dx;
+                cy += dy;
+            }
+            res
+        };
+
+        const DIRS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        Ok((0..r)
+            .cartesian_product(0..c)
+            .map(|(x, y)| DIRS.map(|(dx, dy)| look_dir(x as isize, y as isize, dx, dy)))
+            .collect())
+    }
+
+    fn part1(dirs: &Self::Input) -> Self::Part1 {
+        // a tree is viewable from the side if dir_vals[0]
+        // is strictly greater than all heights in that direction
+        dirs.iter()
+            .filter(|views| {
+                views
+                    .iter()
+                    .any(|dir_vals| dir_vals[1..].iter().all(|&h| h < dir_vals[0]))
+            })
+            .count()
+    }
+
+    fn part2(dirs: &Self::Input) -> Self::Part2 {
+        let mut part2 = 0;
+        for views in dirs {
+            let mut view_prod = 1;
+            for dir_vals in views {
+                // the view distance is up to the first tree at least as tall
+                let len = dir_vals.len();
+                let dist = (1..len).find(|&i| dir_vals[0] <= dir_vals[i]);
+                view_prod *= dist.unwrap_or(len - 1);
+            }
+            part2 = part2.max(view_prod);
+        }
+        part2
+    }
+}
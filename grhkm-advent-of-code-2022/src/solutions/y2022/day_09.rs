@@ -0,0 +1,113 @@
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
+use std::collections::HashSet;
+use std::ops::{Add, Sub};
+
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+pub struct Pos {
+    x: i32,
+    y: i32,
+}
+
+impl Pos {
+    fn new(x: i32, y: i32) -> Pos {
+        Pos { x, y }
+    }
+}
+
+impl Add<Pos> for Pos {
+    type Output = Pos;
+    fn add(self, pt: Pos) -> Pos {
+        Pos::new(pt.x + self.x, pt.y + self.y)
+    }
+}
+
+impl Sub<Pos> for Pos {
+    type Output = Pos;
+    fn sub(self, pt: Pos) -> Pos {
+        Pos::new(pt.x - self.x, pt.y - self.y)
+    }
+}
+
+fn sgn(x: i32) -> i32 {
+    if x > 0 {
+        return 1;
+    }
+    if x < 0 {
+        return -1;
+    }
+    0
+}
+
+fn get_dir(contents: &str, dir: &str) -> ParseResult<Pos> {
+    match dir {
+        "R" => Ok(Pos::new(1, 0)),
+        "L" => Ok(Pos::new(-1, 0)),
+        "U" => Ok(Pos::new(0, 1)),
+        "D" => Ok(Pos::new(0, -1)),
+        _ => Err(ParseError::new(
+            contents,
+            dir,
+            format!("Unknown direction {dir:?}"),
+        )),
+    }
+}
+
+fn pull(head: Pos, tail: Pos) -> Pos {
+    // If close enough, don't move
+    // Otherwise, pull in whichever direction changes
+    let mut dir = tail - head;
+    if dir.x.abs() <= 1 && dir.y.abs() <= 1 {
+        dir = Pos::new(0, 0);
+    } else {
+        // whichever direction changes, move that way
+        dir.x = sgn(dir.x);
+        dir.y = sgn(dir.y);
+    }
+    tail + dir
+}
+
+fn simulate(chains: usize, moves: &[(Pos, isize)]) -> usize {
+    let mut vis: HashSet<Pos> = HashSet::new();
+    let mut chain_pos: Vec<Pos> = vec![Pos::new(0, 0); chains];
+
+    vis.insert(chain_pos[chains - 1]);
+    for &(dir, len) in moves {
+        for _ in 0..len {
+            chain_pos[0] = chain_pos[0] + dir;
+            for i in 1..chains {
+                chain_pos[i] = pull(chain_pos[i - 1], chain_pos[i]);
+            }
+            vis.insert(chain_pos[chains - 1]);
+        }
+    }
+
+    vis.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<(Pos, isize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .split('\n')
+            .map(|line| {
+                let (dir, len) = parser::split_once(contents, line, " ")?;
+                let len = parser::token::<isize>(contents, len)?;
+                Ok((get_dir(contents, dir)?, len))
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Self::Input) -> Self::Part1 {
+        simulate(2, moves)
+    }
+
+    fn part2(moves: &Self::Input) -> Self::Part2 {
+        simulate(10, moves)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    // the value of x during each cycle
+    type Input = Vec<isize>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let mut x_pos: Vec<isize> = vec![1];
+        let mut prev = 1;
+
+        for line in contents.lines() {
+            match line.split_whitespace().collect::<Vec<&str>>()[..] {
+                ["noop"] => x_pos.push(prev),
+                ["addx", arg] => {
+                    x_pos.push(prev);
+                    prev += parser::token::<isize>(contents, arg)?;
+                    x_pos.push(prev);
+                }
+                _ => {
+                    let msg = format!("Unknown instruction {line:?}");
+                    return Err(ParseError::new(contents, line, msg));
+                }
+            }
+        }
+
+        Ok(x_pos)
+    }
+
+    fn part1(x_pos: &Self::Input) -> Self::Part1 {
+        let part1: usize = (20..221)
+            .step_by(40)
+            .map(|i| i * x_pos[i - 1] as usize)
+            .sum();
+        part1.to_string()
+    }
+
+    fn part2(x_pos: &Self::Input) -> Self::Part2 {
+        let mut part2: String = "\n".to_owned();
+        for y in 0..6 {
+            for x in 0..40 {
+                if (x as isize - x_pos[y * 40 + x]).abs() <= 1 {
+                    part2 += "#";
+                } else {
+                    part2 += ".";
+                }
+            }
+            part2 += "\n";
+        }
+        part2
+    }
+}
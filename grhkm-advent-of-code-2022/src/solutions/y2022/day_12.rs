@@ -0,0 +1,117 @@
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
+use std::collections::VecDeque;
+
+fn flood_fill(
+    r: usize,
+    c: usize,
+    queue: &mut VecDeque<(usize, usize, usize)>,
+    dist: &mut [Vec<usize>],
+    grid: &[Vec<usize>],
+) {
+    while let Some((x, y, dt)) = queue.pop_front() {
+        dist[x][y] = dt;
+
+        let x = x as i32;
+        let y = y as i32;
+        for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if nx < 0 || nx >= r as i32 || ny < 0 || ny >= c as i32 {
+                continue;
+            }
+
+            let nx = nx as usize;
+            let ny = ny as usize;
+
+            if grid[nx][ny] > grid[x as usize][y as usize] + 1 {
+                continue;
+            }
+
+            if dt + 1 < dist[nx][ny] {
+                dist[nx][ny] = dt + 1;
+                queue.push_back((nx, ny, dt + 1));
+            }
+        }
+    }
+}
+
+pub struct Heightmap {
+    grid: Vec<Vec<usize>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+fn shortest_path(map: &Heightmap, starts: impl Iterator<Item = (usize, usize)>) -> usize {
+    let r = map.grid.len();
+    let c = map.grid[0].len();
+
+    let mut dist = vec![vec![usize::MAX; c]; r];
+    let mut queue = starts.map(|(x, y)| (x, y, 0)).collect();
+    flood_fill(r, c, &mut queue, &mut dist, &map.grid);
+
+    dist[map.end.0][map.end.1]
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let get_val = |l: &str, i: usize, c: char| match c {
+            'S' => Ok(100),
+            'E' => Ok(120),
+            'a'..='z' => Ok(c as usize - 'a' as usize),
+            _ => Err(ParseError::new(
+                contents,
+                &l[i..],
+                format!("Expected a height, not {c:?}"),
+            )),
+        };
+
+        let mut grid = contents
+            .lines()
+            .map(|l| l.char_indices().map(|(i, c)| get_val(l, i, c)).collect())
+            .collect::<ParseResult<Vec<Vec<usize>>>>()?;
+        let mut sx = 0;
+        let mut sy = 0;
+        let mut ex = 0;
+        let mut ey = 0;
+
+        for (i, row) in grid.iter_mut().enumerate() {
+            for (j, g) in row.iter_mut().enumerate() {
+                if *g == 100 {
+                    sx = i;
+                    sy = j;
+                    *g = 0;
+                }
+                if *g == 120 {
+                    ex = i;
+                    ey = j;
+                    *g = 25;
+                }
+            }
+        }
+
+        Ok(Heightmap {
+            grid,
+            start: (sx, sy),
+            end: (ex, ey),
+        })
+    }
+
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        shortest_path(map, [map.start].into_iter())
+    }
+
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let starts = map.grid.iter().enumerate().flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &g)| g == 0)
+                .map(move |(j, _)| (i, j))
+        });
+        shortest_path(map, starts)
+    }
+}
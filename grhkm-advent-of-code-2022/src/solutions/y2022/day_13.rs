@@ -0,0 +1,167 @@
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
+use std::cmp::min;
+
+fn parse_arr(s: &str) -> Vec<&str> {
+    if s == "[]" {
+        return Vec::new();
+    }
+
+    let s = &s[1..s.len()];
+    let mut v = Vec::new();
+    let mut prev_index = 0;
+    let mut level = 0;
+
+    for (i, c) in s.char_indices() {
+        if c == '[' {
+            level += 1;
+        }
+        if c == ']' {
+            level -= 1;
+        }
+
+        if (c == ',') && level == 0 {
+            v.push(&s[prev_index..i]);
+            prev_index = i + 1;
+        }
+    }
+    v.push(&s[prev_index..s.len() - 1]);
+    v
+}
+
+fn cmp_str(a: &str, b: &str) -> Option<bool> {
+    if a.is_empty() && b.is_empty() {
+        return None;
+    } else if a.is_empty() {
+        return Some(false);
+    } else if b.is_empty() {
+        return Some(true);
+    }
+
+    let is_list_a = a.starts_with('[');
+    let is_list_b = b.starts_with('[');
+
+    if !is_list_a && !is_list_b {
+        let a = a.parse::<usize>().unwrap();
+        let b = b.parse::<usize>().unwrap();
+        if a != b {
+            return Some(a < b);
+        }
+        None
+    } else if is_list_a && is_list_b {
+        // extract data
+        let list_a = parse_arr(a);
+        let list_b = parse_arr(b);
+
+        for i in 0..min(list_a.len(), list_b.len()) {
+            let cmp = cmp_str(list_a[i], list_b[i]);
+            if let Some(c) = cmp {
+                return Some(c);
+            }
+        }
+
+        if list_a.len() != list_b.len() {
+            return Some(list_a.len() < list_b.len());
+        }
+
+        None
+    } else if is_list_a {
+        cmp_str(a, &format!("[{b}]"))
+    } else {
+        cmp_str(&format!("[{a}]"), b)
+    }
+}
+
+// Packets are compared as they are, so only check that they're well formed, down to
+// every number fitting in a usize
+fn check_packet(contents: &str, line: &str) -> ParseResult<String> {
+    let error = |i: usize, msg: &str| Err(ParseError::new(contents, &line[i..], msg));
+    if !line.starts_with('[') {
+        return error(0, "Expected a list");
+    }
+
+    // the last thing seen, with '0' standing for a whole number
+    let mut prev = None;
+    let mut level = 0;
+    let mut i = 0;
+    while let Some(c) = line[i..].chars().next() {
+        match (c, prev) {
+            ('[', None | Some('[' | ',')) => level += 1,
+            (']', _) if level == 0 => return error(i, "Unmatched ']'"),
+            (']', Some('[' | ']' | '0')) => level -= 1,
+            (',', Some(']' | '0')) if level > 0 => {}
+            ('0'..='9', Some('[' | ',')) => {
+                let len = line[i..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(line.len() - i);
+                parser::token::<usize>(contents, &line[i..i + len])?;
+                prev = Some('0');
+                i += len;
+                continue;
+            }
+            _ => return error(i, "Expected a number or a list"),
+        }
+        prev = Some(c);
+        i += c.len_utf8();
+    }
+    if level != 0 {
+        return error(line.len(), "Expected ']'");
+    }
+    Ok(line.to_string())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let packets = contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| check_packet(contents, line))
+            .collect::<ParseResult<Vec<_>>>()?;
+        if packets.len() % 2 == 1 {
+            let last = contents.trim_end().lines().last().unwrap_or("");
+            return Err(ParseError::new(contents, last, "Expected packets in pairs"));
+        }
+        Ok(packets)
+    }
+
+    fn part1(packets: &Self::Input) -> Self::Part1 {
+        let mut part1 = 0;
+        for (i, pair) in packets.chunks(2).enumerate() {
+            let res = cmp_str(&pair[0], &pair[1]);
+            if res == Some(true) {
+                part1 += i + 1;
+            }
+        }
+        part1
+    }
+
+    fn part2(packets: &Self::Input) -> Self::Part2 {
+        let mut groups = packets.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        groups.push("[[2]]");
+        groups.push("[[6]]");
+
+        let n = groups.len();
+        for _ in 0..n - 1 {
+            for j in 0..n - 1 {
+                if cmp_str(groups[j], groups[j + 1]) == Some(false) {
+                    groups.swap(j, j + 1)
+                }
+            }
+        }
+
+        let mut part2 = 1;
+        for (i, &g) in groups.iter().enumerate() {
+            if g == "[[2]]" || g == "[[6]]" {
+                part2 *= i + 1;
+            }
+        }
+        part2
+    }
+}
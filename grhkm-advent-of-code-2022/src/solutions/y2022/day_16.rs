@@ -0,0 +1,152 @@
+#![allow(clippy::needless_range_loop)]
+
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cell::RefCell;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+
+struct Data<'a> {
+    name: &'a str,
+    val: usize,
+    valves: Vec<&'a str>,
+}
+
+lazy_static! {
+    static ref PARSE: Regex =
+        Regex::new(r"Valve (.+) has flow rate=(\d+); tunnels? leads? to valves? (.+)").unwrap();
+}
+
+impl<'a> Data<'a> {
+    fn new(contents: &str, s: &'a str) -> ParseResult<Data<'a>> {
+        let caps = PARSE.captures(s).ok_or_else(|| {
+            let msg = "Expected \"Valve AA has flow rate=0; tunnels lead to valves BB, CC\"";
+            ParseError::new(contents, s, msg)
+        })?;
+        let name = caps.get(1).unwrap();
+        let val = caps.get(2).unwrap();
+        let valves = caps.get(3).unwrap();
+        Ok(Data {
+            name: name.as_str(),
+            val: parser::token(contents, val.as_str())?,
+            valves: valves.as_str().split(", ").collect(),
+        })
+    }
+}
+
+fn calc_dist(graph: &[Vec<usize>], dist: &mut Vec<Vec<usize>>) {
+    let n = graph.len();
+    *dist = vec![vec![1000000; n]; n];
+    for u in 0..n {
+        for &v in &graph[u] {
+            dist[u][v] = 1;
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                dist[i][j] = min(dist[i][j], dist[i][k] + dist[k][j]);
+            }
+        }
+    }
+}
+
+fn dfs(
+    cur: usize,
+    rem: usize,
+    t: usize,
+    part2: bool,
+    dist: &Vec<Vec<usize>>,
+    vals: &Vec<usize>,
+    positive: &Vec<usize>,
+) -> usize {
+    let mut res = 0;
+    if part2 {
+        res = dfs(0, rem, 26, false, dist, vals, positive);
+    }
+    for (i, &dt) in positive.iter().enumerate() {
+        if (rem & (1 << i)) == 0 && dist[cur][dt] < t {
+            let new_t = t - dist[cur][dt] - 1;
+            let new_rem = rem | (1 << i);
+            let mut new_res = vals[dt] * new_t;
+            new_res += dfs(dt, new_rem, new_t, part2, dist, vals, positive);
+            res = max(res, new_res);
+        }
+    }
+    res
+}
+
+pub struct Valves {
+    src: usize,
+    dist: Vec<Vec<usize>>,
+    vals: Vec<usize>,
+    positive: Vec<usize>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Valves;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let datas = contents
+            .lines()
+            .map(|s| Data::new(contents, s))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        // map strings to indices
+        let name_mp = RefCell::new(HashMap::<&str, usize>::new());
+        let mut positive = Vec::new();
+        let mut graph = Vec::new();
+        let mut vals = Vec::new();
+
+        let name_to_idx = |s: &str| {
+            name_mp
+                .borrow()
+                .get(s)
+                .copied()
+                .ok_or_else(|| ParseError::new(contents, s, format!("Unknown valve {s:?}")))
+        };
+
+        for (i, data) in datas.iter().enumerate() {
+            name_mp.borrow_mut().insert(data.name, i);
+        }
+
+        for (i, data) in datas.into_iter().enumerate() {
+            vals.push(data.val);
+            name_mp.borrow_mut().insert(data.name, i);
+            if data.val > 0 {
+                positive.push(i);
+            }
+
+            let mut edges = Vec::new();
+            for valve in data.valves {
+                edges.push(name_to_idx(valve)?);
+            }
+            graph.push(edges);
+        }
+
+        let mut dist = Vec::new();
+        calc_dist(&graph, &mut dist);
+
+        let src = name_to_idx("AA")?;
+        Ok(Valves {
+            src,
+            dist,
+            vals,
+            positive,
+        })
+    }
+
+    fn part1(v: &Self::Input) -> Self::Part1 {
+        dfs(v.src, 0, 30, false, &v.dist, &v.vals, &v.positive)
+    }
+
+    fn part2(v: &Self::Input) -> Self::Part2 {
+        dfs(v.src, 0, 26, true, &v.dist, &v.vals, &v.positive)
+    }
+}
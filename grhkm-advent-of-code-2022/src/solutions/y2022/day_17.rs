@@ -1,6 +1,8 @@
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
 
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
 use lazy_static::lazy_static;
 
 lazy_static! {
@@ -37,26 +39,20 @@ fn check(pts: &[(i64, i64)], rocks: &HashSet<(i64, i64)>) -> bool {
 }
 
 const N: i64 = 1000000000000;
-pub fn solve(contents: &str) -> (usize, usize) {
-    let instructions = contents
-        .chars()
-        .map(|c| if c == '>' { 1 } else { -1 })
-        .collect::<Vec<i64>>();
-
+// height of the tower after n rocks, skipping ahead once the top rows repeat
+fn simulate(instructions: &[i64], n: i64) -> i64 {
     let mut period = 1;
     let mut period_inc = 0;
 
     let mut max_y = -1;
     let mut rocks = HashSet::new();
 
-    let mut part1 = 0;
-
     // Place block by block, while keeping track of the top
     // 100 rows and see if it ever repeats.
     let mut k = 0;
     let mut ptr = 0;
     let mut height_map = HashMap::new();
-    while k < N && (period == 1 || k % period != N % period) {
+    while k < n && (period == 1 || k % period != n % period) {
         // Base shape
         let mut cur_rock = shift(&SHAPES[(k % 5) as usize], 2, max_y + 4);
         k += 1;
@@ -84,10 +80,6 @@ pub fn solve(contents: &str) -> (usize, usize) {
             max_y = max(max_y, rock.1);
         }
 
-        if k == 2022 {
-            part1 = max_y + 1;
-        }
-
         // Extract top 70 rows
         if max_y >= 69 {
             let mut top_70_rows = Vec::new();
@@ -108,7 +100,36 @@ pub fn solve(contents: &str) -> (usize, usize) {
         }
     }
 
-    let part2 = max_y + 1 + (N - k) / period * period_inc;
+    max_y + 1 + (n - k) / period * period_inc
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .char_indices()
+            .map(|(i, c)| match c {
+                '>' => Ok(1),
+                '<' => Ok(-1),
+                _ => Err(ParseError::new(
+                    contents,
+                    &contents[i..],
+                    format!("Expected '<' or '>', not {c:?}"),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Self::Part1 {
+        simulate(instructions, 2022) as usize
+    }
 
-    (part1 as usize, part2 as usize)
+    fn part2(instructions: &Self::Input) -> Self::Part2 {
+        simulate(instructions, N) as usize
+    }
 }
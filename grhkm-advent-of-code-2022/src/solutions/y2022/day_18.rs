@@ -0,0 +1,107 @@
+use crate::parser::{self, ParseResult};
+use crate::solver::Solution;
+use std::collections::HashSet;
+use std::ops::Add;
+
+#[derive(Eq, Copy, Clone, Hash, PartialEq)]
+pub struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Add<Point> for Point {
+    type Output = Point;
+    fn add(self, other: Self) -> Self {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+fn parse_point(contents: &str, s: &str) -> ParseResult<Point> {
+    let (x, s) = parser::split_once(contents, s, ",")?;
+    let (y, z) = parser::split_once(contents, s, ",")?;
+    Ok(Point {
+        x: parser::token(contents, x)?,
+        y: parser::token(contents, y)?,
+        z: parser::token(contents, z)?,
+    })
+}
+
+static DIRS: [Point; 6] = [
+    Point { x: 0, y: 0, z: 1 },
+    Point { x: 0, y: 0, z: -1 },
+    Point { x: 0, y: 1, z: 0 },
+    Point { x: 0, y: -1, z: 0 },
+    Point { x: 1, y: 0, z: 0 },
+    Point { x: -1, y: 0, z: 0 },
+];
+
+fn dfs(pt: Point, points: &HashSet<Point>, vis: &mut HashSet<Point>) {
+    if pt.x < -5 || pt.y < -5 || pt.z < -5 || pt.x > 25 || pt.y > 25 || pt.z > 25 {
+        return;
+    }
+
+    vis.insert(pt);
+    for dir in DIRS {
+        let new_pt = pt + dir;
+        if !points.contains(&new_pt) && !vis.contains(&new_pt) {
+            dfs(new_pt, points, vis);
+        }
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Point>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents.lines().map(|c| parse_point(contents, c)).collect()
+    }
+
+    fn part1(points: &Self::Input) -> Self::Part1 {
+        let mut cnt1 = 0;
+        for &pt in points {
+            for dir in DIRS {
+                if !points.contains(&(pt + dir)) {
+                    cnt1 += 1;
+                }
+            }
+        }
+        cnt1
+    }
+
+    fn part2(points: &Self::Input) -> Self::Part2 {
+        let mut exterior = HashSet::new();
+        let mut cnt2 = 0;
+
+        // Fix potential recursion stack overflow
+        stacker::grow(64 * 1024 * 1024, || {
+            dfs(
+                Point {
+                    x: -1,
+                    y: -1,
+                    z: -1,
+                },
+                points,
+                &mut exterior,
+            );
+        });
+
+        for &pt in points {
+            for dir in DIRS {
+                let new_pt = pt + dir;
+                if !points.contains(&new_pt) && exterior.contains(&new_pt) {
+                    cnt2 += 1;
+                }
+            }
+        }
+        cnt2
+    }
+}
@@ -1,6 +1,8 @@
 // TODO: Remove this from day 19 is finished
 #![allow(unused_assignments, dead_code)]
 
+use crate::parser::{self, ParseError, ParseResult};
+use crate::solver::Solution;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::cmp::{max, Ordering, PartialEq, PartialOrd};
@@ -14,7 +16,7 @@ struct Resource {
 }
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Blueprint {
+pub struct Blueprint {
     idx: i64,
     ore_costs: Resource,
     clay_costs: Resource,
@@ -64,13 +66,15 @@ impl PartialOrd for Resource {
 }
 
 impl Blueprint {
-    fn new(s: &str) -> Blueprint {
+    fn new(contents: &str, s: &str) -> ParseResult<Blueprint> {
         lazy_static! {
             static ref RE : Regex = Regex::new(r"Blueprint (\d+): Each ore robot costs? (\d+) ores?. Each clay robot costs? (\d+) ores?. Each obsidian robot costs? (\d+) ores? and (\d+) clays?. Each geode robot costs (\d+) ores? and (\d+) obsidians?.").unwrap();
         }
-        let caps = RE.captures(s).unwrap();
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| ParseError::new(contents, s, "Expected a blueprint"))?;
 
-        let idx = caps.get(1).unwrap().as_str().parse().unwrap();
+        let idx = parser::token(contents, caps.get(1).unwrap().as_str())?;
         let ore_ore = 0;
         let clay_ore = 0;
         let obsidian_ore = 0;
@@ -78,7 +82,7 @@ impl Blueprint {
         let geode_ore = 0;
         let geode_obsidian = 0;
 
-        Blueprint {
+        Ok(Blueprint {
             idx,
             ore_costs: Resource {
                 ore: ore_ore,
@@ -98,7 +102,7 @@ impl Blueprint {
                 obsidian: geode_obsidian,
                 ..Default::default()
             },
-        }
+        })
     }
 }
 
@@ -133,12 +137,27 @@ fn dfs(
     0
 }
 
-pub fn solve(contents: &str) -> (usize, usize) {
-    let _blueprints: Vec<Blueprint> = contents.lines().map(Blueprint::new).collect();
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .lines()
+            .map(|s| Blueprint::new(contents, s))
+            .collect()
+    }
 
-    // println!("r1 > r2? {}", r1 > r2);
-    // TODO: Solve day 19
-    todo!();
+    fn part1(_blueprints: &Self::Input) -> Self::Part1 {
+        // println!("r1 > r2? {}", r1 > r2);
+        // TODO: Solve day 19
+        todo!();
+    }
 
-    // (0, 0)
+    fn part2(_blueprints: &Self::Input) -> Self::Part2 {
+        todo!();
+    }
 }
@@ -0,0 +1,70 @@
+use crate::parser::{self, ParseResult};
+use crate::solver::Solution;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Item {
+    val: i64,
+    idx: usize,
+}
+
+fn mod_add(x: i64, y: i64, m: i64) -> i64 {
+    let r = (x + y) % m;
+    (r + m) % m
+}
+
+fn solve_arr(arr: &[Item], times: usize) -> i64 {
+    let mut arr = arr.to_owned();
+    let len = arr.len();
+    for _ in 0..times {
+        for i in 0..len {
+            // Find original item
+            let j = (0..len).find(|&k| arr[k].idx == i).unwrap();
+            let item = arr[j];
+            arr.remove(j);
+
+            // Insert item at new position
+            let new_j = mod_add(j as i64, item.val, (len - 1) as i64) as usize;
+            arr.insert(new_j, item);
+        }
+    }
+
+    // Find position of 0
+    let pos = (0..len).find(|&k| arr[k].val == 0).unwrap();
+    arr[(pos + 1000) % len].val + arr[(pos + 2000) % len].val + arr[(pos + 3000) % len].val
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Item>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        contents
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                Ok(Item {
+                    val: parser::token(contents, l)?,
+                    idx: i,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(arr: &Self::Input) -> Self::Part1 {
+        solve_arr(arr, 1) as usize
+    }
+
+    fn part2(arr: &Self::Input) -> Self::Part2 {
+        let arr = arr
+            .iter()
+            .map(|item| Item {
+                val: item.val * 811589153,
+                idx: item.idx,
+            })
+            .collect::<Vec<_>>();
+        solve_arr(&arr, 10) as usize
+    }
+}
@@ -1,3 +1,5 @@
+use crate::parser::{self, ParseResult};
+use crate::solver::Solution;
 use poly::fraction::Fraction;
 use poly::poly::{One, Poly, Solvable, Zero};
 use std::collections::HashMap;
@@ -31,7 +33,7 @@ impl FromStr for Expr {
         // Check if s is empty
         let s = s.trim();
         if s.is_empty() {
-            return Err("".to_string());
+            return Err("Expected an expression".to_string());
         }
 
         // Try to split into three parts
@@ -40,12 +42,13 @@ impl FromStr for Expr {
             [s1, "-", s2] => Ok(Expr::Sub(s1.to_string(), s2.to_string())),
             [s1, "*", s2] => Ok(Expr::Mul(s1.to_string(), s2.to_string())),
             [s1, "/", s2] => Ok(Expr::Div(s1.to_string(), s2.to_string())),
-            _ => Err(format!("Failed to parse {s} into Expr")),
+            _ => Err("Expected a number or an operation like \"abcd + efgh\"".to_string()),
         }
     }
 }
 
-struct MonkeyTree {
+#[derive(Clone)]
+pub struct MonkeyTree {
     root: Option<String>,
     var: Option<String>,
     tree: HashMap<String, Expr>,
@@ -60,15 +63,8 @@ impl MonkeyTree {
         }
     }
 
-    fn insert<T>(&mut self, line: T)
-    where
-        T: Display + Into<String>,
-    {
-        let line = line.to_string();
-        let (name, expr) = line.split_once(": ").unwrap();
-        self.tree
-            .entry(name.to_string())
-            .or_insert(expr.parse().unwrap());
+    fn insert(&mut self, name: &str, expr: Expr) {
+        self.tree.entry(name.to_string()).or_insert(expr);
     }
 
     fn set_root<T>(&mut self, root: T)
@@ -94,7 +90,7 @@ impl MonkeyTree {
         let node = node.to_string();
         if let Some(var) = &self.var {
             if &node == var {
-                println!("[!] Found variable");
+                // println!("[!] Found variable");
                 return Poly::new(&vec![Fraction::zero(), Fraction::one()]);
             }
         }
@@ -106,7 +102,7 @@ impl MonkeyTree {
 
         // If `var` is set, we always subtract and return a root
         if self.var.is_some() && Some(node) == self.root {
-            println!("OP: {expr:?}");
+            // println!("OP: {expr:?}");
             return match expr.unwrap() {
                 Expr::Add(s1, s2) => self.eval_node(s1) - self.eval_node(s2),
                 Expr::Sub(s1, s2) => self.eval_node(s1) - self.eval_node(s2),
@@ -144,7 +140,7 @@ impl MonkeyTree {
         }
 
         let eq = self.eval_node(self.root.as_ref().unwrap());
-        println!("EQ: {eq:?}");
+        // println!("EQ: {eq:?}");
         let sols = eq.roots();
 
         if sols.len() != 1 {
@@ -155,29 +151,43 @@ impl MonkeyTree {
     }
 }
 
-pub fn solve(contents: &str) -> (usize, usize) {
-    let mut tree = MonkeyTree::new();
+pub struct Day21;
 
-    for line in contents.lines() {
-        tree.insert(line);
-    }
+impl Solution for Day21 {
+    type Input = MonkeyTree;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let mut tree = MonkeyTree::new();
 
-    tree.set_root("root");
-    let root_eval = tree.eval();
-    let part1 = root_eval.get(0usize);
+        for line in contents.lines() {
+            let (name, expr) = parser::split_once(contents, line, ": ")?;
+            tree.insert(name, parser::token(contents, expr)?);
+        }
 
-    if root_eval.deg() != 0 || part1.denom() != 1 {
-        panic!("Error: {root_eval} is not an integer");
+        tree.set_root("root");
+        Ok(tree)
     }
-    let part1 = part1.num() as usize;
 
-    tree.set_var("humn");
-    let part2 = tree.solve_match();
+    fn part1(tree: &Self::Input) -> Self::Part1 {
+        let root_eval = tree.eval();
+        let part1 = root_eval.get(0usize);
 
-    if part2.denom() != 1 {
-        panic!("Error: {part2} is not an integer");
+        if root_eval.deg() != 0 || part1.denom() != 1 {
+            panic!("Error: {root_eval} is not an integer");
+        }
+        part1.num() as usize
     }
-    let part2 = part2.num() as usize;
 
-    (part1, part2)
+    fn part2(tree: &Self::Input) -> Self::Part2 {
+        let mut tree = tree.clone();
+        tree.set_var("humn");
+        let part2 = tree.solve_match();
+
+        if part2.denom() != 1 {
+            panic!("Error: {part2} is not an integer");
+        }
+        part2.num() as usize
+    }
 }
@@ -0,0 +1,23 @@
+use crate::parser::ParseResult;
+use crate::solver::Solution;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        Ok(contents.to_string())
+    }
+
+    fn part1(_contents: &Self::Input) -> Self::Part1 {
+        // TOOD: Solve day 22
+        todo!();
+    }
+
+    fn part2(_contents: &Self::Input) -> Self::Part2 {
+        todo!();
+    }
+}
@@ -1,3 +1,5 @@
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
 use crate::utils::*;
 use std::cmp::{max, min};
 use std::collections::{HashMap, HashSet};
@@ -89,33 +91,61 @@ fn print_grid(grid: &HashSet<Point>) {
     println!();
 }
 
-pub fn solve(contents: &str) -> (usize, usize) {
-    let grid = contents
-        .lines()
-        .map(|l| l.chars().collect())
-        .collect::<Vec<Vec<char>>>();
-
-    // extract elf positions
-    let mut elves: HashSet<Point> = HashSet::new();
-    for (x, row) in grid.iter().enumerate() {
-        for (y, &chr) in row.iter().enumerate() {
-            if chr == '#' {
-                let x = x as i64;
-                let y = y as i64;
-                elves.insert(Point::new(x, y));
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HashSet<Point>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let grid = contents
+            .lines()
+            .map(|l| {
+                l.char_indices()
+                    .map(|(i, c)| match c {
+                        '#' | '.' => Ok(c),
+                        _ => Err(ParseError::new(
+                            contents,
+                            &l[i..],
+                            format!("Expected '#' or '.', not {c:?}"),
+                        )),
+                    })
+                    .collect()
+            })
+            .collect::<ParseResult<Vec<Vec<char>>>>()?;
+
+        // extract elf positions
+        let mut elves: HashSet<Point> = HashSet::new();
+        for (x, row) in grid.iter().enumerate() {
+            for (y, &chr) in row.iter().enumerate() {
+                if chr == '#' {
+                    let x = x as i64;
+                    let y = y as i64;
+                    elves.insert(Point::new(x, y));
+                }
             }
         }
+
+        Ok(elves)
     }
 
-    let mut part1 = 0;
-    let mut r = 1;
-    while round(&mut elves, r - 1) {
-        if r == 10 {
-            let (minx, miny, maxx, maxy) = get_bounding_box(&elves);
-            part1 = ((maxx - minx + 1) * (maxy - miny + 1)) as usize - elves.len();
+    fn part1(elves: &Self::Input) -> Self::Part1 {
+        let mut elves = elves.clone();
+        for r in 0..10 {
+            round(&mut elves, r);
         }
-        r += 1;
+
+        let (minx, miny, maxx, maxy) = get_bounding_box(&elves);
+        ((maxx - minx + 1) * (maxy - miny + 1)) as usize - elves.len()
     }
 
-    (part1, r)
+    fn part2(elves: &Self::Input) -> Self::Part2 {
+        let mut elves = elves.clone();
+        let mut r = 1;
+        while round(&mut elves, r - 1) {
+            r += 1;
+        }
+        r
+    }
 }
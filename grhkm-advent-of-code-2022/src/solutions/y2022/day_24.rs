@@ -0,0 +1,178 @@
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
+use crate::utils::*;
+
+fn round(grid: &[Vec<Vec<char>>], rd: i64) -> Vec<Vec<Vec<char>>> {
+    let row = grid.len();
+    let col = grid[0].len();
+    let mut new_grid = vec![vec![vec![]; col]; row];
+
+    let row = row as i64;
+    let col = col as i64;
+
+    // Simulate the movement and return a new grid
+    for r in 0..row {
+        for c in 0..col {
+            for &chr in &grid[r as usize][c as usize] {
+                let pos = Point::new(r, c);
+                let mut new_pos = pos
+                    + match chr {
+                        '#' => Point::new(0, 0),
+                        '^' => Point::new(-rd, 0),
+                        'v' => Point::new(rd, 0),
+                        '<' => Point::new(0, -rd),
+                        '>' => Point::new(0, rd),
+                        _ => unreachable!(),
+                    };
+
+                // Wrap around
+                if chr != '#' {
+                    let nrow = row - 2;
+                    let ncol = col - 2;
+                    new_pos.x = (new_pos.x % nrow - 1 + nrow) % nrow + 1;
+                    new_pos.y = (new_pos.y % ncol - 1 + ncol) % ncol + 1;
+                }
+                new_grid[new_pos.x as usize][new_pos.y as usize].push(chr);
+            }
+        }
+    }
+
+    new_grid
+}
+
+pub struct Valley {
+    graph: HashMap<(usize, Point), Vec<(usize, Point)>>,
+    src: Point,
+    dst: Point,
+}
+
+// BFS for the shortest path that reaches dst after `trips` trips
+// between src and dst
+fn shortest_path(valley: &Valley, trips: usize) -> usize {
+    let Valley { graph, src, dst } = valley;
+    let (src, dst) = (*src, *dst);
+
+    let mut vis = HashSet::new();
+    let mut deque = VecDeque::new();
+
+    deque.push_back(((0, src), 0, 0));
+    vis.insert((0, src, 0));
+    while let Some(((rd, cur), dist, checkpoints)) = deque.pop_front() {
+        // Check checkpoints
+        let mut new_checkpoints = checkpoints;
+        if cur == dst && new_checkpoints % 2 == 0 {
+            new_checkpoints += 1;
+            if new_checkpoints == trips {
+                return dist;
+            }
+        } else if cur == src && new_checkpoints % 2 == 1 {
+            new_checkpoints += 1;
+        }
+
+        // Find next step
+        if !graph.contains_key(&(rd, cur)) {
+            continue;
+        }
+        for (new_rd, dest) in &graph[&(rd, cur)] {
+            let new_rd = *new_rd;
+            let dest = *dest;
+
+            if !vis.contains(&(new_rd, dest, new_checkpoints)) {
+                vis.insert((new_rd, dest, new_checkpoints));
+                deque.push_back(((new_rd, dest), dist + 1, new_checkpoints));
+            }
+        }
+    }
+
+    usize::MAX
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        // We store a list of characters in each of grid[i][j]
+        let grid = contents
+            .lines()
+            .map(|l| {
+                l.char_indices()
+                    .map(|(i, c)| match c {
+                        '.' => Ok(vec![]),
+                        '#' | '<' | '>' | '^' | 'v' => Ok(vec![c]),
+                        _ => Err(ParseError::new(
+                            contents,
+                            &l[i..],
+                            format!("Unexpected {c:?} in the valley"),
+                        )),
+                    })
+                    .collect()
+            })
+            .collect::<ParseResult<Vec<Vec<Vec<char>>>>>()?;
+        let row = grid.len();
+        let Some(col) = grid.first().map(Vec::len) else {
+            return Err(ParseError::new(contents, contents, "Expected a valley"));
+        };
+
+        // Grid repeats every `cycles` iterations
+        let cycles = lcm(row as i64, col as i64) as usize;
+        let grids = (0..=cycles)
+            .into_par_iter()
+            .map(|rd| round(&grid, rd as i64))
+            .collect::<Vec<_>>();
+
+        let graph: HashMap<(usize, Point), Vec<(usize, Point)>> = (0..cycles)
+            .into_par_iter()
+            .map(|rd| {
+                // Make edges from rd to rd + 1
+                let cur_grid = &grids[rd];
+                let nxt_grid = &grids[rd + 1];
+
+                let mut graph = HashMap::new();
+                let mut insert_edge = |x: Point, y: Point| {
+                    graph
+                        .entry((rd, x))
+                        .or_insert(Vec::new())
+                        .push(((rd + 1) % cycles, y));
+                };
+
+                for r in 0..row {
+                    for c in 0..col {
+                        let pt1 = Point::new(r as i64, c as i64);
+                        if !cur_grid[r][c].is_empty() {
+                            continue;
+                        }
+                        if nxt_grid[r][c].is_empty() {
+                            insert_edge(pt1, pt1);
+                        }
+                        for pt2 in pt1.dirs4() {
+                            if pt2.is_valid(row as i64, col as i64) && nxt_grid[pt2].is_empty() {
+                                insert_edge(pt1, pt2);
+                            }
+                        }
+                    }
+                }
+                graph
+            })
+            .flatten()
+            .collect();
+
+        let src = Point::new(0, 1);
+        let dst = Point::new(row as i64 - 1, col as i64 - 2);
+        Ok(Valley { graph, src, dst })
+    }
+
+    fn part1(valley: &Self::Input) -> Self::Part1 {
+        shortest_path(valley, 1)
+    }
+
+    fn part2(valley: &Self::Input) -> Self::Part2 {
+        shortest_path(valley, 3)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::parser::{ParseError, ParseResult};
+use crate::solver::Solution;
+const DIGIT_MAP: [(char, i128); 5] = [('0', 0), ('1', 1), ('2', 2), ('-', -1), ('=', -2)];
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    // the sum of all the numbers
+    type Input = i128;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input> {
+        let symbols_to_int = |syms: &str| -> ParseResult<i128> {
+            let mut res = 0;
+            for (i, c) in syms.char_indices() {
+                let Some(&(_, digit)) = DIGIT_MAP.iter().find(|(sym, _)| *sym == c) else {
+                    let msg = format!("Expected a SNAFU digit, not {c:?}");
+                    return Err(ParseError::new(contents, &syms[i..], msg));
+                };
+                res = res * 5 + digit;
+            }
+            Ok(res)
+        };
+
+        contents.lines().map(symbols_to_int).sum()
+    }
+
+    fn part1(&num: &Self::Input) -> Self::Part1 {
+        // Assuming num > 0
+        assert!(num > 0);
+        let mut num = num;
+        let mut syms = "".to_string();
+        while num > 0 {
+            // Find LSD by considering modulo 5
+            for (sym, digit) in DIGIT_MAP {
+                if (num - digit) % 5 == 0 {
+                    syms.push(sym);
+                    num = (num - digit) / 5;
+                    break;
+                }
+            }
+        }
+        syms.chars().rev().collect()
+    }
+
+    fn part2(_: &Self::Input) -> Self::Part2 {
+        "There is no part 2! Merry Christmas!".to_string()
+    }
+}
@@ -0,0 +1,31 @@
+use crate::solver::Registry;
+
+register_solutions! {
+    2022;
+    0 => day_00::Day00,
+    1 => day_01::Day01,
+    2 => day_02::Day02,
+    3 => day_03::Day03,
+    4 => day_04::Day04,
+    5 => day_05::Day05,
+    6 => day_06::Day06,
+    7 => day_07::Day07,
+    8 => day_08::Day08,
+    9 => day_09::Day09,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    13 => day_13::Day13,
+    14 => day_14::Day14,
+    15 => day_15::Day15,
+    16 => day_16::Day16,
+    17 => day_17::Day17,
+    18 => day_18::Day18,
+    19 => day_19::Day19,
+    20 => day_20::Day20,
+    21 => day_21::Day21,
+    22 => day_22::Day22,
+    23 => day_23::Day23,
+    24 => day_24::Day24,
+    25 => day_25::Day25,
+}
@@ -1,69 +1,129 @@
-use std::time::Instant;
+use lazy_static::lazy_static;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
 
-use crate::solutions::*;
+use crate::error::{Error, Result};
+use crate::parser::ParseResult;
+use crate::puzzle::Puzzle;
+use crate::solutions;
 
-#[allow(dead_code)]
-pub enum SolverType {
-    BigInteger,
-    Integer,
-    String,
+pub const DAYS: usize = 25;
+
+// Implemented by every day module, the input is parsed once and shared by
+// both parts, whose answers only need to be printable
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(contents: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
 }
 
-pub const DAYS: usize = 25;
-pub const SOLS: [(*const (), SolverType); DAYS + 1] = [
-    (day_00::solve as *const (), SolverType::Integer),
-    (day_01::solve as *const (), SolverType::Integer),
-    (day_02::solve as *const (), SolverType::Integer),
-    (day_03::solve as *const (), SolverType::Integer),
-    (day_04::solve as *const (), SolverType::Integer),
-    (day_05::solve as *const (), SolverType::String),
-    (day_06::solve as *const (), SolverType::Integer),
-    (day_07::solve as *const (), SolverType::Integer),
-    (day_08::solve as *const (), SolverType::Integer),
-    (day_09::solve as *const (), SolverType::Integer),
-    (day_10::solve as *const (), SolverType::String),
-    (day_11::solve as *const (), SolverType::Integer),
-    (day_12::solve as *const (), SolverType::Integer),
-    (day_13::solve as *const (), SolverType::Integer),
-    (day_14::solve as *const (), SolverType::Integer),
-    (day_15::solve as *const (), SolverType::Integer),
-    (day_16::solve as *const (), SolverType::Integer),
-    (day_17::solve as *const (), SolverType::Integer),
-    (day_18::solve as *const (), SolverType::Integer),
-    (day_19::solve as *const (), SolverType::Integer),
-    (day_20::solve as *const (), SolverType::Integer),
-    (day_21::solve as *const (), SolverType::Integer),
-    (day_22::solve as *const (), SolverType::Integer),
-    (day_23::solve as *const (), SolverType::Integer),
-    (day_24::solve as *const (), SolverType::Integer),
-    (day_25::solve as *const (), SolverType::String),
-];
-
-pub fn solve(contents: &str, day: usize) -> (String, String) {
-    let (solver, solver_type) = &SOLS[day];
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub answer: String,
+    pub time: Duration,
+}
+
+// The parts that were asked for, along with how long each phase took
+#[derive(Clone, Debug)]
+pub struct Output {
+    pub parse: Duration,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
 
+impl Output {
+    pub fn total(&self) -> Duration {
+        let parts = [&self.part1, &self.part2];
+        self.parse
+            + parts
+                .iter()
+                .flat_map(|a| a.as_ref())
+                .map(|a| a.time)
+                .sum::<Duration>()
+    }
+}
+
+// `part` selects a single part to run, otherwise both are
+pub type Solver = fn(&str, Option<usize>) -> ParseResult<Output>;
+
+fn time<T: Display>(f: impl FnOnce() -> T) -> Answer {
     let now = Instant::now();
-    let (part1, part2): (String, String) = unsafe {
-        match solver_type {
-            SolverType::BigInteger => {
-                use num_bigint::BigUint;
-                let code =
-                    std::mem::transmute::<&*const (), &fn(&str) -> (BigUint, BigUint)>(solver);
-                let (part1, part2) = code(contents);
-                (part1.to_string(), part2.to_string())
-            }
-            SolverType::Integer => {
-                let code = std::mem::transmute::<&*const (), &fn(&str) -> (usize, usize)>(solver);
-                let (part1, part2) = code(contents);
-                (part1.to_string(), part2.to_string())
-            }
-            SolverType::String => {
-                let code = std::mem::transmute::<&*const (), &fn(&str) -> (String, String)>(solver);
-                let (part1, part2) = code(contents);
-                (part1, part2)
-            }
+    let answer = f().to_string();
+    Answer {
+        answer,
+        time: now.elapsed(),
+    }
+}
+
+fn run<S: Solution>(contents: &str, part: Option<usize>) -> ParseResult<Output> {
+    let now = Instant::now();
+    let input = S::parse(contents)?;
+    let parse = now.elapsed();
+
+    let part1 = (part != Some(2)).then(|| time(|| S::part1(&input)));
+    let part2 = (part != Some(1)).then(|| time(|| S::part2(&input)));
+    Ok(Output {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<Puzzle, Solver>,
+}
+
+impl Registry {
+    pub fn register<S: Solution>(&mut self, year: usize, day: usize) {
+        let puzzle = Puzzle::new(year, day);
+        if self.solvers.insert(puzzle, run::<S>).is_some() {
+            panic!("err: {puzzle} is registered twice");
         }
+    }
+
+    pub fn get(&self, puzzle: Puzzle) -> Option<Solver> {
+        self.solvers.get(&puzzle).copied()
+    }
+
+    pub fn solver(&self, puzzle: Puzzle) -> Result<Solver> {
+        self.get(puzzle)
+            .ok_or_else(|| Error::Solver(format!("No solution registered for {puzzle}")))
+    }
+
+    pub fn years(&self) -> Vec<usize> {
+        let mut years = self.solvers.keys().map(|p| p.year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    pub fn days(&self, year: usize) -> impl Iterator<Item = usize> + '_ {
+        self.solvers
+            .keys()
+            .filter(move |p| p.year == year)
+            .map(|p| p.day)
+    }
+}
+
+lazy_static! {
+    pub static ref REGISTRY: Registry = {
+        let mut registry = Registry::default();
+        solutions::register(&mut registry);
+        registry
     };
-    println!("Day {day:02} time taken: {}ms", now.elapsed().as_millis());
-    (part1, part2)
+}
+
+pub fn run_puzzle(contents: &str, puzzle: Puzzle, part: Option<usize>) -> Result<Output> {
+    let solver = REGISTRY.solver(puzzle)?;
+    Ok(solver(contents, part)?)
+}
+
+pub fn solve(contents: &str, puzzle: Puzzle) -> Result<(String, String)> {
+    let Output { part1, part2, .. } = run_puzzle(contents, puzzle, None)?;
+    Ok((part1.unwrap().answer, part2.unwrap().answer))
 }
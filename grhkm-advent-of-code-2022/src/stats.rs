@@ -0,0 +1,191 @@
+use crate::page;
+use crate::puzzle::Puzzle;
+use crate::runner::{self, Status};
+use crate::solver::REGISTRY;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+lazy_static! {
+    static ref PRE: Regex = Regex::new(r"(?s)<pre>(.*?)</pre>").unwrap();
+}
+
+// When we finished a part and where that put us on the global leaderboard
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartTime {
+    // Like "00:05:10", or ">24h" once it took a day
+    pub time: String,
+    pub rank: usize,
+    pub score: usize,
+}
+
+// The rows of the personal times page at `/{year}/leaderboard/self`, by day and then
+// part. Parts not finished yet are `None`
+pub fn personal_times(body: &str) -> BTreeMap<usize, [Option<PartTime>; 2]> {
+    let Some(captures) = PRE.captures(body) else {
+        return BTreeMap::new();
+    };
+    page::text(&captures[1])
+        .lines()
+        .filter_map(|line| {
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            let (day, parts) = tokens.split_first()?;
+            let day = day.parse().ok()?;
+            let part = |i: usize| {
+                let [time, rank, score] = parts.get(i * 3..i * 3 + 3)? else {
+                    return None;
+                };
+                Some(PartTime {
+                    time: time.to_string(),
+                    rank: rank.parse().ok()?,
+                    score: score.parse().ok()?,
+                })
+            };
+            Some((day, [part(0), part(1)]))
+        })
+        .collect()
+}
+
+// Whether our solution to a part works, from running it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Working,
+    Missing,
+    Broken(Status),
+    // Nothing to run it on
+    Unchecked,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Working => write!(f, "Ok"),
+            Check::Missing => write!(f, "No solution"),
+            Check::Broken(status) => write!(f, "{status}"),
+            Check::Unchecked => write!(f, "No input to check with"),
+        }
+    }
+}
+
+// How long `stats` gives each part when not told otherwise, as some days take a while on
+// a real input
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+// Runs one part on `contents`, which is best a sample so that slow days don't hold us up
+pub fn check(
+    puzzle: Puzzle,
+    level: usize,
+    contents: Option<&str>,
+    timeout: Option<Duration>,
+) -> Check {
+    if REGISTRY.get(puzzle).is_none() {
+        return Check::Missing;
+    }
+    let Some(contents) = contents else {
+        return Check::Unchecked;
+    };
+    match runner::run(puzzle, contents, Some(level), timeout).status {
+        Status::Ok => Check::Working,
+        // registered, but still `todo!()`
+        Status::Unimplemented => Check::Missing,
+        status => Check::Broken(status),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: usize,
+    pub stars: usize,
+    pub times: [Option<PartTime>; 2],
+    // Only the starred parts are checked
+    pub checks: Vec<Check>,
+}
+
+impl DayStats {
+    // Starred, yet we have nothing here that solves it
+    pub fn flagged(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| matches!(check, Check::Missing | Check::Broken(_)))
+    }
+}
+
+// Puts together the calendar and the personal times page, checking our solution to each
+// starred part against whatever `contents` has for its day
+pub fn collect<F>(
+    year: usize,
+    calendar: &str,
+    times: &str,
+    contents: F,
+    timeout: Option<Duration>,
+) -> Vec<DayStats>
+where
+    F: Fn(Puzzle) -> Option<String>,
+{
+    let mut times = personal_times(times);
+    let mut days = page::calendar(calendar);
+    days.sort_unstable();
+
+    days.into_iter()
+        .map(|(day, stars)| {
+            let puzzle = Puzzle::new(year, day);
+            let input = (stars > 0).then(|| contents(puzzle)).flatten();
+            let checks = (1..=stars)
+                .map(|level| check(puzzle, level, input.as_deref(), timeout))
+                .collect();
+            DayStats {
+                day,
+                stars,
+                times: times.remove(&day).unwrap_or_default(),
+                checks,
+            }
+        })
+        .collect()
+}
+
+pub fn format(stats: &[DayStats]) -> String {
+    let mut out = format!(
+        "{:>3}  {:<5}  {:>8} {:>6} {:>5}  {:>8} {:>6} {:>5}  Solution\n",
+        "Day", "Stars", "Part 1", "Rank", "Score", "Part 2", "Rank", "Score"
+    );
+    for day in stats {
+        let times = day
+            .times
+            .iter()
+            .map(|part| match part {
+                Some(PartTime { time, rank, score }) => format!("{time:>8} {rank:>6} {score:>5}"),
+                None => format!("{:>8} {:>6} {:>5}", "-", "-", "-"),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        let solution = if day.checks.iter().all(|check| *check == Check::Working) {
+            if day.checks.is_empty() { "" } else { "Ok" }.to_string()
+        } else {
+            (1..)
+                .zip(&day.checks)
+                .map(|(level, check)| format!("Part {level}: {check}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let stars = "*".repeat(day.stars);
+        let row = format!("{:>3}  {stars:<5}  {times}  {solution}", day.day);
+        out += &format!("{}\n", row.trim_end());
+    }
+
+    let flagged = stats
+        .iter()
+        .filter(|day| day.flagged())
+        .map(|day| day.day.to_string())
+        .collect::<Vec<_>>();
+    if flagged.is_empty() {
+        out += "\nEvery starred part has a working solution\n";
+    } else {
+        out += &format!(
+            "\nStarred without a working solution: days {}\n",
+            flagged.join(", ")
+        );
+    }
+    out
+}
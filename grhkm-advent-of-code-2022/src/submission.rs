@@ -0,0 +1,113 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+use std::time::Duration;
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m ?)?(?:(\d+)s )?left to wait").unwrap();
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+// Outcome of posting an answer, parsed from the server's response page
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { hint: Option<Hint> },
+    RateLimited { wait: Duration },
+    AlreadySolved,
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Verdict {
+    pub fn parse(body: &str) -> Verdict {
+        // The message is the text of the <article> block, with the links stripped out
+        let article = ARTICLE
+            .captures(body)
+            .map_or(body, |captures| captures.get(1).unwrap().as_str());
+        let text = TAG.replace_all(article, "");
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Verdict::Wrong { hint }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = WAIT.captures(&text).map_or(0, |captures| {
+                let minutes = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds = captures.get(2).map_or(0, |s| s.as_str().parse().unwrap());
+                minutes * 60 + seconds
+            });
+            Verdict::RateLimited {
+                wait: Duration::from_secs(wait),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            // also sent for a finished part, see `resolve_level`
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    // The server sends the very same page for a finished part and for a part 2 whose
+    // part 1 isn't solved yet, so tell them apart by the answers the day page shows
+    pub fn resolve_level(self, level: usize, answers: usize) -> Verdict {
+        match self {
+            Verdict::WrongLevel if answers >= level => Verdict::AlreadySolved,
+            verdict => verdict,
+        }
+    }
+
+    // Distinct per outcome so that scripts can react, 1 and 2 are left for
+    // general errors and bad command line arguments
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Verdict::Correct => 0,
+            Verdict::Wrong { .. } => 3,
+            Verdict::RateLimited { .. } => 4,
+            Verdict::AlreadySolved => 5,
+            Verdict::WrongLevel => 6,
+            Verdict::Unknown(_) => 7,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "Correct!"),
+            Verdict::Wrong { hint: None } => write!(f, "Wrong answer"),
+            Verdict::Wrong { hint: Some(hint) } => write!(f, "Wrong answer, {hint}"),
+            Verdict::RateLimited { wait } => {
+                let wait = wait.as_secs();
+                write!(f, "Rate limited, wait {wait}s before trying again")
+            }
+            Verdict::AlreadySolved => write!(f, "Already solved"),
+            Verdict::WrongLevel => write!(f, "Wrong level, is the previous part solved?"),
+            Verdict::Unknown(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::Result;
+use crate::puzzle::Puzzle;
+use reqwest::StatusCode;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Puzzles unlock at midnight US-Eastern, and December is always on EST (UTC-5)
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+// Where the time comes from, so that waiting can be tested without waiting
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+pub fn unlock_time(puzzle: Puzzle) -> SystemTime {
+    let days = days_from_civil(puzzle.year as u64, 12, puzzle.day as u64);
+    UNIX_EPOCH + Duration::from_secs(days * 86400 + UNLOCK_HOUR_UTC * 3600)
+}
+
+// How long until `puzzle` unlocks, zero once it has
+pub fn time_left(puzzle: Puzzle, clock: &impl Clock) -> Duration {
+    unlock_time(puzzle)
+        .duration_since(clock.now())
+        .unwrap_or_default()
+}
+
+// Like "1d 02:03:04", leaving out the days when there are none
+pub fn format_countdown(left: Duration) -> String {
+    let secs = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+    let (days, hours, mins, secs) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    let clock = format!("{hours:02}:{mins:02}:{secs:02}");
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+// Sleeps until `puzzle` unlocks, calling `tick` with the time left about once a second
+pub fn wait(puzzle: Puzzle, clock: &impl Clock, mut tick: impl FnMut(Duration)) {
+    loop {
+        let left = time_left(puzzle, clock);
+        if left.is_zero() {
+            return;
+        }
+        tick(left);
+        clock.sleep(left.min(Duration::from_secs(1)));
+    }
+}
+
+// Tries again while the server says the puzzle isn't unlocked yet, as our clock may be a
+// little ahead of its
+pub fn retry<T>(
+    clock: &impl Clock,
+    attempts: usize,
+    delay: Duration,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    for _ in 1..attempts {
+        match f() {
+            Err(e) if e.status() == Some(StatusCode::NOT_FOUND) => clock.sleep(delay),
+            result => return result,
+        }
+    }
+    f()
+}
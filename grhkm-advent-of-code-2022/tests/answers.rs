@@ -0,0 +1,79 @@
+use advent_of_code_2022::answers::{KnownAnswers, Mismatch};
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::solver;
+use pretty_assertions::assert_eq;
+use std::fs;
+
+const PUZZLE: Puzzle = Puzzle::new(2022, 0);
+
+#[test]
+fn test_save_and_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("answers.tsv");
+    let mut answers = KnownAnswers::load(&path).unwrap();
+    assert!(answers.puzzles().is_empty());
+
+    answers.insert(Puzzle::new(2022, 10), 2, "\n#.\n.#\n".to_string());
+    answers.insert(PUZZLE, 2, "60".to_string());
+    answers.insert(PUZZLE, 1, "6".to_string());
+    answers.save().unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "2022\t0\t1\t6\n2022\t0\t2\t60\n2022\t10\t2\t\\n#.\\n.#\\n\n"
+    );
+
+    let answers = KnownAnswers::load(&path).unwrap();
+    assert_eq!(answers.puzzles(), [PUZZLE, Puzzle::new(2022, 10)]);
+    assert_eq!(answers.get(Puzzle::new(2022, 10), 2), Some("\n#.\n.#\n"));
+    assert_eq!(answers.part(PUZZLE), None);
+    assert_eq!(answers.part(Puzzle::new(2022, 10)), Some(2));
+
+    fs::write(&path, "2022\t0\tone\t6\n").unwrap();
+    assert!(KnownAnswers::load(&path).is_err());
+}
+
+#[test]
+fn test_check_reports_mismatches() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut answers = KnownAnswers::load(dir.path().join("answers.tsv")).unwrap();
+    answers.insert(PUZZLE, 1, "6".to_string());
+    answers.insert(PUZZLE, 2, "61".to_string());
+
+    let output = solver::run_puzzle("1\n2\n3", PUZZLE, None).unwrap();
+    let mismatches = answers.check(PUZZLE, &output);
+    assert_eq!(
+        mismatches,
+        [Mismatch {
+            puzzle: PUZZLE,
+            level: 2,
+            expected: "61".to_string(),
+            actual: "60".to_string(),
+        }]
+    );
+    assert_eq!(
+        mismatches[0].to_string(),
+        "Year 2022 Day #00 level 2:\n- 61\n+ 60\n"
+    );
+}
+
+#[test]
+fn test_drawn_answers_are_not_compared() {
+    let day_10 = Puzzle::new(2022, 10);
+    let dir = tempfile::tempdir().unwrap();
+    let mut answers = KnownAnswers::load(dir.path().join("answers.tsv")).unwrap();
+    // as harvested from the page
+    answers.insert(day_10, 1, "13140".to_string());
+    answers.insert(day_10, 2, "ZGCJZJFL".to_string());
+
+    let input = vec!["noop"; 240].join("\n");
+    let mut output = solver::run_puzzle(&input, day_10, None).unwrap();
+    output.part1.as_mut().unwrap().answer = "13140".to_string();
+    assert!(output.part2.as_ref().unwrap().answer.contains("\n#"));
+    assert_eq!(answers.check(day_10, &output), []);
+    assert_eq!(answers.unchecked(day_10, &output), [2]);
+
+    // a saved drawing still has to match
+    answers.insert(day_10, 2, "\n#.\n.#\n".to_string());
+    assert_eq!(answers.check(day_10, &output).len(), 1);
+    assert!(answers.unchecked(day_10, &output).is_empty());
+}
@@ -0,0 +1,81 @@
+use advent_of_code_2022::bench::{self, Benchmark, Stats};
+use advent_of_code_2022::format::Format;
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::runner::Status;
+use advent_of_code_2022::solver::REGISTRY;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+const PUZZLE: Puzzle = Puzzle::new(2022, 1);
+
+fn micros(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|&us| Duration::from_micros(us))
+        .collect()
+}
+
+#[test]
+fn test_stats_summarise_samples() {
+    let stats = Stats::new(PUZZLE, &micros(&[40, 10, 30, 20, 1000]));
+    assert_eq!(stats.runs, 5);
+    assert_eq!(stats.min_us, 10);
+    assert_eq!(stats.median_us, 30);
+    assert_eq!(stats.mean_us, 220);
+    assert_eq!(stats.p95_us, 1000);
+
+    // even counts average the middle pair
+    let stats = Stats::new(PUZZLE, &micros(&[1, 2, 3, 4]));
+    assert_eq!(stats.median_us, 2);
+    assert_eq!(stats.p95_us, 4);
+
+    let samples = (1..=100).collect::<Vec<_>>();
+    assert_eq!(Stats::new(PUZZLE, &micros(&samples)).p95_us, 95);
+}
+
+#[test]
+fn test_stats_serialise_to_json() {
+    let benchmark = Benchmark {
+        puzzle: PUZZLE,
+        status: Status::Ok,
+        stats: Some(Stats::new(PUZZLE, &micros(&[5]))),
+    };
+    let failed = Benchmark::fetch_failed(Puzzle::new(2022, 2), "Not unlocked".to_string());
+    let json: serde_json::Value =
+        serde_json::from_str(&bench::format_json(&[benchmark, failed])).unwrap();
+    assert_eq!(json[0]["day"], 1);
+    assert_eq!(json[0]["median_us"], 5);
+    assert_eq!(json[1]["status"], "Failed to fetch");
+    assert_eq!(json[1]["message"], "Not unlocked");
+    assert_eq!(json[1]["median_us"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_bench_isolates_each_day() {
+    let bench = |day, contents| {
+        let puzzle = Puzzle::new(2022, day);
+        bench::bench(puzzle, REGISTRY.solver(puzzle).unwrap(), contents, None, 3)
+    };
+    let ok = bench(0, "1\n2\n3");
+    assert_eq!(ok.status, Status::Ok);
+    assert_eq!(ok.stats.unwrap().runs, 3);
+
+    // still `todo!()`
+    let unimplemented = bench(22, "");
+    assert_eq!(unimplemented.status, Status::Unimplemented);
+    assert!(unimplemented.stats.is_none());
+
+    let invalid = bench(0, "1\nx");
+    assert!(matches!(invalid.status, Status::InvalidInput(_)));
+
+    let failed = Benchmark::fetch_failed(Puzzle::new(2022, 2), "Not unlocked".to_string());
+    let table = bench::format(&[unimplemented, failed], Format::Table);
+    assert_eq!(
+        table.lines().skip(1).collect::<Vec<_>>(),
+        [
+            "Year 2022 Day #22 Unimplemented        -            -            -            -            -",
+            "Year 2022 Day #02 Failed to fetch      -            -            -            -            -",
+            "Year 2022 Day #02: Failed to fetch: Not unlocked",
+        ]
+    );
+}
@@ -0,0 +1,357 @@
+use advent_of_code_2022::config::HttpConfig;
+use advent_of_code_2022::fetcher::Fetcher;
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::session::Session;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+pub const SESSION: &str = "session=mock";
+
+#[derive(Default)]
+struct State {
+    inputs: HashMap<Puzzle, String>,
+    answers: HashMap<(Puzzle, usize), String>,
+    solved: HashSet<(Puzzle, usize)>,
+    rate_limit: Option<u64>,
+    leaderboards: HashMap<String, String>,
+    requests: Vec<String>,
+    user_agents: Vec<String>,
+}
+
+// A tiny stand-in for adventofcode.com, serving inputs and judging answers
+// from whatever the test registered with it
+pub struct MockServer {
+    url: String,
+    state: Arc<Mutex<State>>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    user_agent: String,
+    body: String,
+}
+
+impl MockServer {
+    pub fn start() -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let thread_state = state.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &thread_state);
+            }
+        });
+
+        MockServer { url, state }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn input(self, puzzle: Puzzle, input: &str) -> MockServer {
+        let mut state = self.state.lock().unwrap();
+        state.inputs.insert(puzzle, input.to_string());
+        drop(state);
+        self
+    }
+
+    pub fn answer(self, puzzle: Puzzle, level: usize, answer: &str) -> MockServer {
+        let mut state = self.state.lock().unwrap();
+        state.answers.insert((puzzle, level), answer.to_string());
+        drop(state);
+        self
+    }
+
+    // Marks a part as already solved, so that its answer shows on the day page
+    pub fn solved(self, puzzle: Puzzle, level: usize) -> MockServer {
+        self.state.lock().unwrap().solved.insert((puzzle, level));
+        self
+    }
+
+    // Serves `json` as the private leaderboard `id`, whatever the year
+    pub fn leaderboard(self, id: u64, json: &str) -> MockServer {
+        let mut state = self.state.lock().unwrap();
+        state
+            .leaderboards
+            .insert(format!("{id}.json"), json.to_string());
+        drop(state);
+        self
+    }
+
+    pub fn rate_limit(&self, wait: Option<u64>) {
+        self.state.lock().unwrap().rate_limit = wait;
+    }
+
+    // Every request seen so far, as "METHOD /path"
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    pub fn user_agents(&self) -> Vec<String> {
+        self.state.lock().unwrap().user_agents.clone()
+    }
+}
+
+// No waiting and no cache, unless a test asks for them
+pub fn http() -> HttpConfig {
+    HttpConfig {
+        interval: Duration::ZERO,
+        ..HttpConfig::default()
+    }
+}
+
+// Logged in as the mock user
+pub fn mock_session() -> Session {
+    Session::new(None, Some(SESSION.to_string()), None)
+}
+
+pub fn session_fetcher(server: &MockServer, session: Session) -> Fetcher {
+    Fetcher::new(server.url(), session, &http()).unwrap()
+}
+
+pub fn mock_fetcher(server: &MockServer) -> Fetcher {
+    session_fetcher(server, mock_session())
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut cookie = None;
+    let mut user_agent = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(": ")?;
+        match name.to_lowercase().as_str() {
+            "cookie" => cookie = Some(value.to_string()),
+            "user-agent" => user_agent = value.to_string(),
+            "content-length" => content_length = value.parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+    let body = String::from_utf8(body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        cookie,
+        user_agent,
+        body,
+    })
+}
+
+fn page(article: &str) -> String {
+    format!("<!DOCTYPE html>\n<html><body>\n<main>\n<article><p>{article}</p></article>\n</main>\n</body></html>")
+}
+
+fn day_page(state: &State, puzzle: Puzzle) -> String {
+    let mut body = format!(
+        "<article class=\"day-desc\"><h2>--- Day {}: Mock ---</h2></article>\n",
+        puzzle.day
+    );
+    for level in 1..=2 {
+        if !state.solved.contains(&(puzzle, level)) {
+            break;
+        }
+        let answer = &state.answers[&(puzzle, level)];
+        body += &format!("<p>Your puzzle answer was <code>{answer}</code>.</p>\n");
+    }
+    format!("<!DOCTYPE html>\n<html><body>\n<main>\n{body}</main>\n</body></html>")
+}
+
+fn judge(state: &mut State, puzzle: Puzzle, level: usize, answer: &str) -> String {
+    // the same page for a finished part and a locked one, just like the real thing
+    let solved = state.solved.contains(&(puzzle, level));
+    let locked = level == 2 && !state.solved.contains(&(puzzle, 1));
+    let expected = state.answers.get(&(puzzle, level)).cloned();
+    let Some(expected) = expected.filter(|_| !solved && !locked) else {
+        return page(&format!(
+            "You don't seem to be solving the right level.  Did you already complete it? \
+             <a href=\"/{}/day/{}\">[Return to Day {}]</a>",
+            puzzle.year, puzzle.day, puzzle.day
+        ));
+    };
+
+    if let Some(wait) = state.rate_limit {
+        return page(&format!("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {wait}s left to wait."));
+    }
+
+    if answer == expected {
+        state.solved.insert((puzzle, level));
+        return page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+    }
+
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => ".",
+    };
+    page(&format!(
+        "That's not the right answer{hint}.  Please wait one minute before trying again."
+    ))
+}
+
+// The event calendar, whose header shows who is logged in and which links to the
+// days with an input
+fn event_page(state: &State, year: usize, logged_in: bool) -> String {
+    let header = if logged_in {
+        "<div class=\"user\">mock user <span class=\"star-count\">2*</span></div>"
+    } else {
+        "<div><a href=\"/auth/login\">[Log In]</a></div>"
+    };
+
+    let mut calendar = String::new();
+    for day in 1..=25 {
+        let puzzle = Puzzle::new(year, day);
+        if !state.inputs.contains_key(&puzzle) {
+            calendar += &format!("<span class=\"calendar-day{day}\">{day}</span>\n");
+            continue;
+        }
+        let stars = (1..=2)
+            .filter(|&level| state.solved.contains(&(puzzle, level)))
+            .count();
+        let class = ["", " calendar-complete", " calendar-verycomplete"][stars];
+        calendar += &format!(
+            "<a aria-label=\"Day {day}\" href=\"/{year}/day/{day}\" class=\"calendar-day{day}{class}\">{day}</a>\n"
+        );
+    }
+    format!("<!DOCTYPE html>\n<html><body>\n<header>{header}</header>\n<main>\n<pre class=\"calendar\">\n{calendar}</pre>\n</main>\n</body></html>")
+}
+
+// A row for every day with a solved part, each star taking a minute and ranking first
+fn personal_times_page(state: &State, year: usize) -> String {
+    let mut days = state
+        .solved
+        .iter()
+        .filter(|(puzzle, _)| puzzle.year == year)
+        .map(|(puzzle, _)| puzzle.day)
+        .collect::<Vec<_>>();
+    days.sort_unstable_by(|a, b| b.cmp(a));
+    days.dedup();
+
+    let mut rows = String::new();
+    for day in days {
+        rows += &format!("{day:>3}");
+        for level in 1..=2 {
+            if state.solved.contains(&(Puzzle::new(year, day), level)) {
+                rows += "   00:01:00      1    100";
+            } else {
+                rows += &format!("{:>11}{:>7}{:>7}", "-", "-", "-");
+            }
+        }
+        rows += "\n";
+    }
+    page(&format!(
+        "Your personal leaderboard statistics.</p>\n<pre>{rows}</pre><p>"
+    ))
+}
+
+fn route(state: &mut State, request: &Request) -> (u16, String) {
+    let logged_in = request.cookie.as_deref() == Some(SESSION);
+    let path = request.path.trim_matches('/');
+    if let (true, Ok(year)) = (request.method == "GET", path.parse()) {
+        return (200, event_page(state, year, logged_in));
+    }
+
+    if !logged_in {
+        return (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string(),
+        );
+    }
+
+    let parts = path.split('/').collect::<Vec<_>>();
+    if let [year, "leaderboard", "self"] = parts[..] {
+        return match year.parse() {
+            Ok(year) => (200, personal_times_page(state, year)),
+            Err(_) => (404, "404 Not Found".to_string()),
+        };
+    }
+    if let [_, "leaderboard", "private", "view", file] = parts[..] {
+        return match state.leaderboards.get(file) {
+            Some(json) => (200, json.clone()),
+            None => (404, "404 Not Found".to_string()),
+        };
+    }
+    let (year, day, endpoint) = match parts[..] {
+        [year, "day", day] => (year, day, ""),
+        [year, "day", day, endpoint] => (year, day, endpoint),
+        _ => return (404, "404 Not Found".to_string()),
+    };
+    let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+        return (404, "404 Not Found".to_string());
+    };
+    let puzzle = Puzzle::new(year, day);
+
+    match (request.method.as_str(), endpoint) {
+        ("GET", "") if state.inputs.contains_key(&puzzle) => (200, day_page(state, puzzle)),
+        ("GET", "input") => match state.inputs.get(&puzzle) {
+            Some(input) => (200, format!("{input}\n")),
+            None => (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar \
+                 countdown is synchronized with the server time; the link will be enabled on the \
+                 calendar the instant this puzzle becomes available.\n"
+                    .to_string(),
+            ),
+        },
+        ("POST", "answer") => {
+            let mut answer = String::new();
+            let mut level = 0;
+            for (key, value) in request.body.split('&').filter_map(|kv| kv.split_once('=')) {
+                match key {
+                    "answer" => answer = value.replace('+', " "),
+                    "level" => level = value.parse().unwrap_or(0),
+                    _ => {}
+                }
+            }
+            (200, judge(state, puzzle, level, &answer))
+        }
+        _ => (404, "404 Not Found".to_string()),
+    }
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<State>) {
+    let Some(request) = read_request(&mut stream) else {
+        return;
+    };
+
+    let (status, body) = {
+        let mut state = state.lock().unwrap();
+        state
+            .requests
+            .push(format!("{} {}", request.method, request.path));
+        state.user_agents.push(request.user_agent.clone());
+        route(&mut state, &request)
+    };
+
+    let reason = if status == 200 { "OK" } else { "Error" };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+}
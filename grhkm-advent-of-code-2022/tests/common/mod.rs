@@ -1,2 +1,6 @@
+// Each test crate only uses some of these helpers
+#![allow(dead_code, unused_macros)]
+
+pub mod mock_server;
 #[macro_use]
 pub mod utils;
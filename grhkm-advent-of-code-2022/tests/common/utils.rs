@@ -1,26 +1,26 @@
+use advent_of_code_2022::config::Config;
+use advent_of_code_2022::puzzle::Puzzle;
 use advent_of_code_2022::solver;
 use std::fs;
 
-pub fn get(day: usize) -> Result<String, std::io::Error> {
-    fs::read_to_string(format!("./input/sample/day_{day:02}.in"))
+pub fn get(puzzle: Puzzle) -> Result<String, std::io::Error> {
+    let config = Config::load().unwrap_or_else(|e| panic!("Error: {e}"));
+    fs::read_to_string(config.sample_path(puzzle))
 }
 
-pub fn solve(day: usize) -> (String, String) {
-    let sample = get(day);
-    if sample.is_err() {
-        panic!("Error: {}", sample.unwrap_err());
-    }
-    let sample = sample.unwrap();
-    solver::solve(&sample, day)
+pub fn solve(puzzle: Puzzle) -> (String, String) {
+    let sample = get(puzzle).unwrap_or_else(|e| panic!("Error: {e}"));
+    solver::solve(&sample, puzzle).unwrap()
 }
 
 macro_rules! solutions_tests {
-    ($($name:ident: $value:expr,)*) => {
+    ($year:literal; $($(#[$meta:meta])* $name:ident: $value:expr,)*) => {
     $(
         #[test]
+        $(#[$meta])*
         fn $name() {
-            let (input, (exp_part1, exp_part2)) = $value;
-            let (sol_part1, sol_part2) = solve(input);
+            let (day, (exp_part1, exp_part2)) = $value;
+            let (sol_part1, sol_part2) = solve(Puzzle::new($year, day));
 
             assert_eq!(format!("{exp_part1}"), format!("{sol_part1}"));
             assert_eq!(format!("{exp_part2}"), format!("{sol_part2}"));
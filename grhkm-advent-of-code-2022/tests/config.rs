@@ -0,0 +1,106 @@
+use advent_of_code_2022::config::{Config, HttpConfig};
+use advent_of_code_2022::format::Format;
+use advent_of_code_2022::puzzle::Puzzle;
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn config_file(dir: &Path, name: &str, contents: &str) -> PathBuf {
+    let path = dir.join(format!("{name}.toml"));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_default_paths() {
+    let config = Config::from_files::<PathBuf>(&[]).unwrap();
+    assert_eq!(config, Config::default());
+
+    let puzzle = Puzzle::new(2022, 7);
+    assert_eq!(
+        config.input_path(puzzle),
+        PathBuf::from("./input/2022/day_07.in")
+    );
+    assert_eq!(
+        config.sample_path(puzzle),
+        PathBuf::from("./input/2022/sample/day_07.in")
+    );
+    assert_eq!(config.history_file(), PathBuf::from("./input/history.tsv"));
+}
+
+#[test]
+fn test_user_overrides_project() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = config_file(
+        dir.path(),
+        "project",
+        "input-dir = \"data\"\nyear = 2021\nformat = \"json\"\ntimeout = 10\n\n\
+         [http]\nuser-agent = \"aoc (me@example.com)\"\ninterval = 2\n",
+    );
+    let user = config_file(
+        dir.path(),
+        "user",
+        "sample-dir = \"/tmp/samples\"\nyear = 2022\nconfirm-submit = false\n\n[http]\ninterval = 0.5\n",
+    );
+    let missing = dir.path().join("missing.toml");
+
+    let config = Config::from_files(&[&project, &user, &missing]).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            input_dir: PathBuf::from("data"),
+            sample_dir: Some(PathBuf::from("/tmp/samples")),
+            year: 2022,
+            session_file: None,
+            format: Format::Json,
+            timeout: Some(Duration::from_secs(10)),
+            confirm_submit: false,
+            http: HttpConfig {
+                user_agent: "aoc (me@example.com)".to_string(),
+                interval: Duration::from_millis(500),
+                ..HttpConfig::default()
+            },
+        }
+    );
+    assert_eq!(
+        config.sample_path(Puzzle::new(2022, 1)),
+        PathBuf::from("/tmp/samples/2022/day_01.in")
+    );
+    assert_eq!(config.http().cache_dir, Some(PathBuf::from("data/cache")));
+}
+
+#[test]
+fn test_user_agent_contact() {
+    let http = |user_agent: &str| HttpConfig {
+        user_agent: user_agent.to_string(),
+        ..HttpConfig::default()
+    };
+    assert!(!HttpConfig::default().has_contact());
+    assert!(!http("my-aoc-tool").has_contact());
+    assert!(http("aoc (me@example.com)").has_contact());
+    assert!(http("aoc (https://github.com/me/aoc)").has_contact());
+}
+
+#[test]
+fn test_invalid_config() {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, contents, msg) in [
+        (
+            "unknown",
+            "input_dir = \"data\"\n",
+            "unknown field `input_dir`",
+        ),
+        ("format", "format = \"xml\"\n", "Unknown format \"xml\""),
+        (
+            "timeout",
+            "timeout = -1\n",
+            "timeout must be a positive number",
+        ),
+        ("syntax", "year = \n", "Reading config"),
+    ] {
+        let path = config_file(dir.path(), name, contents);
+        let err = Config::from_files(&[&path]).unwrap_err().to_string();
+        assert!(err.contains(msg), "{name}: {err}");
+    }
+}
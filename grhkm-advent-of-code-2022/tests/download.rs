@@ -0,0 +1,76 @@
+mod common;
+
+use advent_of_code_2022::config::Config;
+use advent_of_code_2022::download::{self, Outcome};
+use advent_of_code_2022::page;
+use advent_of_code_2022::puzzle::Puzzle;
+use common::mock_server::{mock_fetcher, MockServer};
+use pretty_assertions::assert_eq;
+use std::fs;
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(
+        download::parse_days("1-25").unwrap(),
+        (1..=25).collect::<Vec<_>>()
+    );
+    assert_eq!(download::parse_days("7, 1,3-5,4").unwrap(), [1, 3, 4, 5, 7]);
+    for spec in ["", "0-3", "3-1", "1-26", "one", "1-2-3"] {
+        assert!(download::parse_days(spec).is_err(), "{spec:?}");
+    }
+}
+
+#[test]
+fn test_download_unlocked_days() {
+    let server = MockServer::start()
+        .input(Puzzle::new(2022, 1), "1")
+        .input(Puzzle::new(2022, 2), "2\r\n2")
+        .input(Puzzle::new(2022, 3), "<!DOCTYPE html>\n<html></html>")
+        .answer(Puzzle::new(2022, 1), 1, "1")
+        .solved(Puzzle::new(2022, 1), 1);
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let config = Config {
+        input_dir: root.to_path_buf(),
+        ..Config::default()
+    };
+    fs::create_dir_all(root.join("2022")).unwrap();
+    fs::write(config.input_path(Puzzle::new(2022, 1)), "1").unwrap();
+    let fetcher = mock_fetcher(&server);
+
+    let outcomes = download::download(&fetcher, &config, 2022, &[1, 2, 3, 4]).unwrap();
+    let outcomes = outcomes
+        .iter()
+        .map(|(puzzle, outcome)| format!("{}: {outcome}", puzzle.day))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outcomes,
+        [
+            "1: Already cached",
+            "2: Fetched",
+            "3: Failed: Downloading Year 2022 Day #03 input: Expected a puzzle input, got an HTML page (200 OK)",
+            "4: Not unlocked yet",
+        ]
+    );
+    assert_eq!(
+        fs::read_to_string(config.input_path(Puzzle::new(2022, 2))).unwrap(),
+        "2\n2"
+    );
+    assert!(!config.input_path(Puzzle::new(2022, 3)).exists());
+
+    // nothing left that the calendar links to, so nothing is asked for
+    let outcomes = download::download(&fetcher, &config, 2022, &[1, 2]).unwrap();
+    assert!(outcomes.iter().all(|(_, o)| matches!(o, Outcome::Cached)));
+    assert_eq!(
+        server.requests(),
+        [
+            "GET /2022",
+            "GET /2022/day/2/input",
+            "GET /2022/day/3/input"
+        ]
+    );
+
+    let calendar = page::calendar(&fetcher.calendar(2022).unwrap());
+    assert_eq!(calendar, [(1, 1), (2, 0), (3, 0)]);
+}
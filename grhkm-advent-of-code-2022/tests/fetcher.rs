@@ -0,0 +1,307 @@
+mod common;
+
+use advent_of_code_2022::config::HttpConfig;
+use advent_of_code_2022::error::Error;
+use advent_of_code_2022::fetcher::Fetcher;
+use advent_of_code_2022::history::{History, Record, Rejection};
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::session::Session;
+use advent_of_code_2022::submission::{Hint, Verdict};
+use advent_of_code_2022::{consts, page, solver};
+use common::mock_server::{session_fetcher, MockServer, SESSION};
+use pretty_assertions::assert_eq;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const PUZZLE: Puzzle = Puzzle::new(2022, 0);
+
+// A session read from a cookie file in `dir`, which has to outlive it
+fn cookie_file(dir: &Path) -> Session {
+    let path = dir.join("cookie");
+    fs::write(&path, SESSION).unwrap();
+    Session::new(Some(path), None, None)
+}
+
+#[test]
+fn test_fetch_input() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start().input(PUZZLE, "1\n2\n3");
+    let input = session_fetcher(&server, cookie_file(dir.path()))
+        .fetch(PUZZLE)
+        .unwrap();
+    assert_eq!(input, "1\n2\n3");
+    assert_eq!(server.requests(), ["GET /2022/day/0/input"]);
+}
+
+#[test]
+fn test_submit_verdicts() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start()
+        .input(PUZZLE, "1\n2\n3")
+        .answer(PUZZLE, 1, "60");
+    let fetcher = session_fetcher(&server, cookie_file(dir.path()));
+    let submit = |answer| fetcher.submit(PUZZLE, answer, 1).unwrap();
+
+    let too_high = Verdict::Wrong {
+        hint: Some(Hint::TooHigh),
+    };
+    let too_low = Verdict::Wrong {
+        hint: Some(Hint::TooLow),
+    };
+    assert_eq!(submit("61"), too_high);
+    assert_eq!(submit("59"), too_low);
+    assert_eq!(submit("sixty"), Verdict::Wrong { hint: None });
+
+    server.rate_limit(Some(30));
+    assert_eq!(
+        submit("60"),
+        Verdict::RateLimited {
+            wait: Duration::from_secs(30)
+        }
+    );
+    server.rate_limit(None);
+
+    // part 2 is locked until part 1 is solved, and the server says the same either way
+    let verdict = fetcher.submit(PUZZLE, "600", 2).unwrap();
+    assert_eq!(verdict, Verdict::WrongLevel);
+    assert_eq!(submit("60"), Verdict::Correct);
+    assert_eq!(submit("60"), Verdict::AlreadySolved);
+    assert_eq!(
+        server.requests()[4..],
+        [
+            "POST /2022/day/0/answer",
+            "GET /2022/day/0",
+            "POST /2022/day/0/answer",
+            "POST /2022/day/0/answer",
+            "GET /2022/day/0"
+        ]
+    );
+}
+
+#[test]
+fn test_download_and_submit() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start()
+        .input(PUZZLE, "1\n2\n3\n4\n5")
+        .answer(PUZZLE, 1, "15")
+        .answer(PUZZLE, 2, "150");
+    let fetcher = session_fetcher(&server, cookie_file(dir.path()));
+    let history_path = dir.path().join("history.tsv");
+    let mut history = History::load(&history_path).unwrap();
+
+    let input = fetcher.fetch(PUZZLE).unwrap();
+    let (part1, part2) = solver::solve(&input, PUZZLE).unwrap();
+
+    for (level, answer) in [(1, part1), (2, part2)] {
+        assert_eq!(history.check(PUZZLE, level, &answer), Ok(()));
+        let verdict = fetcher.submit(PUZZLE, &answer, level).unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+        history
+            .push(Record {
+                puzzle: PUZZLE,
+                level,
+                answer: answer.clone(),
+                verdict,
+            })
+            .unwrap();
+        assert_eq!(
+            history.check(PUZZLE, level, &answer),
+            Err(Rejection::AlreadySolved { answer })
+        );
+    }
+
+    assert_eq!(
+        server.requests(),
+        [
+            "GET /2022/day/0/input",
+            "POST /2022/day/0/answer",
+            "POST /2022/day/0/answer"
+        ]
+    );
+}
+
+#[test]
+fn test_harvest_answers_from_page() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start()
+        .input(PUZZLE, "1\n2\n3")
+        .answer(PUZZLE, 1, "6")
+        .answer(PUZZLE, 2, "60");
+    let fetcher = session_fetcher(&server, cookie_file(dir.path()));
+    let answers = || page::answers(&fetcher.fetch_page(PUZZLE).unwrap());
+
+    assert!(answers().is_empty());
+    fetcher.submit(PUZZLE, "6", 1).unwrap();
+    assert_eq!(answers(), ["6"]);
+    fetcher.submit(PUZZLE, "60", 2).unwrap();
+    assert_eq!(answers(), ["6", "60"]);
+
+    let locked = fetcher.fetch_page(Puzzle::new(2022, 1)).unwrap_err();
+    assert_eq!(locked.status().unwrap().as_u16(), 404);
+}
+
+#[test]
+fn test_missing_cookie_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start().input(PUZZLE, "1\n2\n3");
+    let path = dir.path().join("missing-cookie");
+
+    let session = Session::new(Some(path.clone()), Some("mock".to_string()), None);
+    let err = session_fetcher(&server, session).fetch(PUZZLE).unwrap_err();
+    assert!(matches!(err, Error::Context { .. }));
+    assert!(err.to_string().starts_with(&format!(
+        "Downloading Year 2022 Day #00 input: Reading cookie file {path:?}: "
+    )));
+    assert_eq!(err.status(), None);
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_reject_error_pages() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start()
+        .input(PUZZLE, "1\r\n2\r\n3")
+        .input(Puzzle::new(2022, 2), "<!DOCTYPE html>\n<html></html>");
+    let fetcher = session_fetcher(&server, cookie_file(dir.path()));
+    assert_eq!(fetcher.fetch(PUZZLE).unwrap(), "1\n2\n3");
+
+    let locked = fetcher.fetch(Puzzle::new(2022, 1)).unwrap_err();
+    assert_eq!(locked.status().unwrap().as_u16(), 404);
+    assert!(locked
+        .to_string()
+        .contains("Please don't repeatedly request this endpoint before it unlocks!"));
+
+    let html = fetcher.fetch(Puzzle::new(2022, 2)).unwrap_err();
+    assert_eq!(
+        html.to_string(),
+        "Downloading Year 2022 Day #02 input: Expected a puzzle input, got an HTML page (200 OK)"
+    );
+
+    let path = dir.path().join("wrong-cookie");
+    fs::write(&path, "session=wrong").unwrap();
+    let session = Session::new(Some(path), None, None);
+    let logged_out = session_fetcher(&server, session).fetch(PUZZLE);
+    assert!(logged_out
+        .unwrap_err()
+        .to_string()
+        .contains("Please log in"));
+}
+
+#[test]
+fn test_session_sources() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start().input(PUZZLE, "1\n2\n3");
+    let user_file = dir.path().join("user-session");
+    fs::write(&user_file, "mock\n").unwrap();
+
+    // a bare token from the environment or the user file is enough
+    for session in [
+        Session::new(None, Some("mock".to_string()), None),
+        Session::new(None, None, Some(user_file.clone())),
+        Session::new(
+            None,
+            Some(SESSION.to_string()),
+            Some(dir.path().join("no-session")),
+        ),
+    ] {
+        assert_eq!(session.cookie().unwrap(), SESSION);
+        assert_eq!(
+            session_fetcher(&server, session).fetch(PUZZLE).unwrap(),
+            "1\n2\n3"
+        );
+    }
+
+    let missing = Session::new(None, None, Some(dir.path().join("no-session")));
+    let err = missing.cookie().unwrap_err();
+    assert!(matches!(err, Error::Usage(_)));
+    assert!(err
+        .to_string()
+        .starts_with("No session cookie, pass --cookie"));
+    assert_eq!(server.requests().len(), 3);
+}
+
+// The only test here that touches the environment
+#[test]
+fn test_session_precedence() {
+    let dir = tempfile::tempdir().unwrap();
+    let cookie = dir.path().join("cookie");
+    fs::write(&cookie, "session=flag").unwrap();
+    let configured = dir.path().join("configured");
+    fs::write(&configured, "session=config").unwrap();
+    let cookie_for = |cookie_file: Option<&Path>| {
+        let session =
+            Session::from_env(cookie_file.map(Path::to_path_buf), Some(configured.clone()));
+        session.cookie().unwrap().to_string()
+    };
+
+    env::set_var(consts::SESSION_ENV, "session=env");
+    assert_eq!(cookie_for(Some(&cookie)), "session=flag");
+    assert_eq!(cookie_for(None), "session=env");
+    env::remove_var(consts::SESSION_ENV);
+    assert_eq!(cookie_for(None), "session=config");
+}
+
+#[test]
+fn test_whoami() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start();
+    let user = session_fetcher(&server, cookie_file(dir.path()))
+        .whoami(2022)
+        .unwrap();
+    assert_eq!(user.as_deref(), Some("mock user 2*"));
+
+    let expired = Session::new(None, Some("expired".to_string()), None);
+    assert_eq!(
+        session_fetcher(&server, expired).whoami(2022).unwrap(),
+        None
+    );
+    assert_eq!(server.requests(), ["GET /2022", "GET /2022"]);
+}
+
+#[test]
+fn test_cache_and_throttle() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start()
+        .input(PUZZLE, "1\n2\n3")
+        .answer(PUZZLE, 1, "6");
+    let cache_dir = dir.path().join("cache");
+    let http = HttpConfig {
+        user_agent: "aoc-test (mock@example.com)".to_string(),
+        interval: Duration::from_millis(200),
+        cache_dir: Some(cache_dir),
+        ..HttpConfig::default()
+    };
+
+    let start = Instant::now();
+    let fetcher = Fetcher::new(server.url(), cookie_file(dir.path()), &http).unwrap();
+    for _ in 0..2 {
+        assert_eq!(fetcher.fetch(PUZZLE).unwrap(), "1\n2\n3");
+        fetcher.fetch_page(PUZZLE).unwrap();
+    }
+    assert!(fetcher.fetch(Puzzle::new(2022, 1)).is_err());
+
+    // a new run starts from what the last one cached, and solving refreshes the page
+    let fetcher = Fetcher::new(server.url(), cookie_file(dir.path()), &http).unwrap();
+    assert_eq!(fetcher.fetch(PUZZLE).unwrap(), "1\n2\n3");
+    fetcher.submit(PUZZLE, "6", 1).unwrap();
+    assert_eq!(page::answers(&fetcher.fetch_page(PUZZLE).unwrap()), ["6"]);
+    // two gaps between the first run's requests, one between the second's
+    assert!(start.elapsed() >= Duration::from_millis(3 * 200));
+
+    assert_eq!(
+        server.requests(),
+        [
+            "GET /2022/day/0/input",
+            "GET /2022/day/0",
+            "GET /2022/day/1/input",
+            "POST /2022/day/0/answer",
+            "GET /2022/day/0",
+        ]
+    );
+    assert!(server
+        .user_agents()
+        .iter()
+        .all(|agent| agent == "aoc-test (mock@example.com)"));
+}
@@ -0,0 +1,58 @@
+use advent_of_code_2022::format::{self, Format};
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::runner::{self, Report};
+use pretty_assertions::assert_eq;
+
+fn reports() -> Vec<Report> {
+    vec![
+        runner::run(Puzzle::new(2022, 0), "1\n2", Some(1), None),
+        Report::fetch_failed(Puzzle::new(2022, 1), "Please log in".to_string()),
+    ]
+}
+
+#[test]
+fn test_parse_format() {
+    for name in format::FORMATS {
+        assert_eq!(name.parse::<Format>().unwrap().to_string(), name);
+    }
+    assert!("yaml".parse::<Format>().is_err());
+}
+
+#[test]
+fn test_csv_and_markdown_escaping() {
+    let rows = [vec![
+        "a,b".to_string(),
+        "say \"hi\"".to_string(),
+        "#.\n.#".to_string(),
+    ]];
+    assert_eq!(
+        format::csv(&["x", "y", "z"], &rows),
+        "x,y,z\n\"a,b\",\"say \"\"hi\"\"\",\"#.\n.#\"\n"
+    );
+    assert_eq!(
+        format::markdown(&["x", "y", "z"], &rows),
+        "| x | y | z |\n| --- | --- | --- |\n| a,b | say \"hi\" | #.<br>.# |\n"
+    );
+}
+
+#[test]
+fn test_run_report_formats() {
+    let json = runner::format(&reports(), Format::Json);
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(json[0]["day"], 0);
+    assert_eq!(json[0]["status"], "Ok");
+    assert_eq!(json[0]["part1"], "3");
+    assert!(json[0]["part2"].is_null());
+    assert!(json[0]["part1_us"].is_u64());
+    assert_eq!(json[1]["status"], "Failed to fetch");
+    assert_eq!(json[1]["message"], "Please log in");
+
+    let csv = runner::format(&reports(), Format::Csv);
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "year,day,status,message,part1,part2,parse_us,part1_us,part2_us,total_us"
+    );
+    assert!(lines[1].starts_with("2022,0,Ok,,3,,"));
+    assert_eq!(lines[2], "2022,1,Failed to fetch,Please log in,,,,,,");
+}
@@ -0,0 +1,77 @@
+use advent_of_code_2022::history::{History, Record, Rejection};
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::submission::{Hint, Verdict};
+use pretty_assertions::assert_eq;
+
+const PUZZLE: Puzzle = Puzzle::new(2022, 1);
+
+fn record(level: usize, answer: &str, verdict: Verdict) -> Record {
+    Record {
+        puzzle: PUZZLE,
+        level,
+        answer: answer.to_string(),
+        verdict,
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("history.tsv");
+    let records = [
+        record(1, "100", Verdict::Wrong { hint: None }),
+        record(1, "42", Verdict::Correct),
+        record(
+            2,
+            "tab\tand\nnewline",
+            Verdict::Unknown("odd\tpage".to_string()),
+        ),
+    ];
+
+    let mut history = History::load(&path).unwrap();
+    for record in records.iter().cloned() {
+        history.push(record).unwrap();
+    }
+
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.records(), &records[..]);
+}
+
+#[test]
+fn test_check() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("history.tsv");
+    let mut history = History::load(&path).unwrap();
+    let too_high = Verdict::Wrong {
+        hint: Some(Hint::TooHigh),
+    };
+    let too_low = Verdict::Wrong {
+        hint: Some(Hint::TooLow),
+    };
+    history.push(record(1, "500", too_high.clone())).unwrap();
+    history.push(record(1, "300", too_high)).unwrap();
+    history.push(record(1, "100", too_low)).unwrap();
+    history
+        .push(record(1, "abc", Verdict::Wrong { hint: None }))
+        .unwrap();
+
+    assert_eq!(history.check(PUZZLE, 1, "abc"), Err(Rejection::KnownWrong));
+    assert_eq!(
+        history.check(PUZZLE, 1, "400"),
+        Err(Rejection::TooHigh { bound: 300 })
+    );
+    assert_eq!(
+        history.check(PUZZLE, 1, "50"),
+        Err(Rejection::TooLow { bound: 100 })
+    );
+    assert_eq!(history.check(PUZZLE, 1, "200"), Ok(()));
+    assert_eq!(history.check(PUZZLE, 2, "400"), Ok(()));
+
+    history.push(record(1, "200", Verdict::Correct)).unwrap();
+    assert_eq!(
+        history.check(PUZZLE, 1, "250"),
+        Err(Rejection::AlreadySolved {
+            answer: "200".to_string()
+        })
+    );
+}
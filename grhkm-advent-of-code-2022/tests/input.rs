@@ -0,0 +1,29 @@
+use advent_of_code_2022::input;
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+#[test]
+fn test_normalize() {
+    assert_eq!(input::normalize("1\r\n2\r\n\r\n3\r\n\r\n"), "1\n2\n\n3");
+    assert_eq!(input::normalize(">><<\n"), ">><<");
+}
+
+#[test]
+fn test_error_pages() {
+    assert_eq!(
+        input::error_page(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        ),
+        Some("Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string())
+    );
+    assert_eq!(
+        input::error_page("<!DOCTYPE html>\n<html><body><main>...</main></body></html>"),
+        Some("Expected a puzzle input, got an HTML page".to_string())
+    );
+    assert_eq!(input::error_page("<<>><>\n"), None);
+    assert_eq!(input::error_page("1\n2\n3"), None);
+
+    let err = input::validate(StatusCode::NOT_FOUND, "404 Not Found").unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    assert!(input::validate(StatusCode::OK, "1\n2\n3").is_ok());
+}
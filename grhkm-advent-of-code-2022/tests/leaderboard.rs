@@ -0,0 +1,102 @@
+mod common;
+
+use advent_of_code_2022::config::HttpConfig;
+use advent_of_code_2022::fetcher::Fetcher;
+use advent_of_code_2022::leaderboard::Leaderboard;
+use advent_of_code_2022::puzzle::Puzzle;
+use common::mock_server::{http, mock_session, MockServer};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+const FIXTURE_2022: &str = include_str!("fixtures/leaderboard_2022.json");
+const FIXTURE_2024: &str = include_str!("fixtures/leaderboard_2024.json");
+
+#[test]
+fn test_parse_leaderboard() {
+    let leaderboard = Leaderboard::parse(FIXTURE_2022).unwrap();
+    assert_eq!(leaderboard.event, 2022);
+    assert_eq!(leaderboard.owner_id, 1001);
+    assert_eq!(leaderboard.members.len(), 3);
+
+    let names = leaderboard
+        .ranked()
+        .iter()
+        .map(|m| (m.display_name(), m.local_score, m.stars))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            ("alice".to_string(), 15, 6),
+            ("grhkm".to_string(), 13, 5),
+            ("(anonymous user #1003)".to_string(), 0, 0),
+        ]
+    );
+
+    let alice = &leaderboard.members["1002"];
+    assert_eq!(
+        alice.star_times(Puzzle::new(2022, 2)),
+        [Duration::from_secs(86400 + 5), Duration::from_secs(90000)]
+    );
+    assert!(alice.star_times(Puzzle::new(2022, 4)).is_empty());
+
+    // newer events add fields of their own
+    let leaderboard = Leaderboard::parse(FIXTURE_2024).unwrap();
+    let owner = &leaderboard.members["7"];
+    assert_eq!(
+        owner.star_times(Puzzle::new(leaderboard.event, 1)),
+        [Duration::from_secs(42)]
+    );
+
+    let err = Leaderboard::parse("<!DOCTYPE html>").unwrap_err();
+    assert!(err.to_string().starts_with("Failed to parse leaderboard: "));
+}
+
+#[test]
+fn test_format_leaderboard() {
+    let leaderboard = Leaderboard::parse(FIXTURE_2022).unwrap();
+    assert_eq!(
+        leaderboard.format(),
+        "       123\n\
+         1) 15  ***  alice\n\
+         2) 13  **+  grhkm\n\
+         3)  0  ...  (anonymous user #1003)\n\
+         \n\
+         Star times after unlock:\n\
+         alice\n  \
+           Day  1:    00:01:00    01:01:40\n  \
+           Day  2: 1d 00:00:05 1d 01:00:00\n  \
+           Day  3:    00:25:00    00:30:00\n\
+         grhkm\n  \
+           Day  1:    00:01:40    00:04:10\n  \
+           Day  2:    00:10:00    00:15:00\n  \
+           Day  3:    00:40:00\n"
+    );
+}
+
+#[test]
+fn test_fetch_leaderboard_once() {
+    let server = MockServer::start().leaderboard(1001, FIXTURE_2022);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let http = HttpConfig {
+        cache_dir: Some(cache_dir.path().to_path_buf()),
+        ..http()
+    };
+
+    for _ in 0..2 {
+        let fetcher = Fetcher::new(server.url(), mock_session(), &http).unwrap();
+        let json = fetcher.leaderboard(2022, 1001).unwrap();
+        assert_eq!(Leaderboard::parse(&json).unwrap().members.len(), 3);
+        assert_eq!(
+            fetcher.leaderboard(2022, 1).unwrap_err().status(),
+            Some(reqwest::StatusCode::NOT_FOUND)
+        );
+    }
+    assert_eq!(
+        server.requests(),
+        [
+            "GET /2022/leaderboard/private/view/1001.json",
+            "GET /2022/leaderboard/private/view/1.json",
+            "GET /2022/leaderboard/private/view/1.json"
+        ]
+    );
+}
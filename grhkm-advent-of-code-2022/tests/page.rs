@@ -0,0 +1,41 @@
+use advent_of_code_2022::page;
+use pretty_assertions::assert_eq;
+
+// Trimmed from a solved day page
+const SOLVED: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2><p>...</p></article>
+<p>Your puzzle answer was <code>CMZ</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>...</p></article>
+<p>Your puzzle answer was <code>M&amp;CD</code>.</p><p class="day-success">Both parts of this puzzle are complete! They provide two gold stars: **</p>
+</main>"#;
+
+#[test]
+fn test_page_answers() {
+    assert_eq!(page::answers(SOLVED), ["CMZ", "M&CD"]);
+    assert!(page::answers("<main><article><p>...</p></article></main>").is_empty());
+}
+
+// Trimmed from an unsolved day page
+const EXAMPLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns:</p>
+<pre><code>Elf &lt;1&gt;</code></pre>
+<p>For example, suppose the Elves finish writing their items' Calories:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<p>In the example above, this is <em>24000</em> Calories (<code><em>24000</em></code>).</p>
+<p>Find the Elf carrying the most, <code><em>24000</em></code>.</p></article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>In the example above, the sum is <code><em>45000</em></code>.</p></article>
+</main>"#;
+
+#[test]
+fn test_page_examples() {
+    assert_eq!(
+        page::code_blocks(EXAMPLE),
+        ["Elf <1>", "1000\n2000\n\n4000\n"]
+    );
+    assert_eq!(page::example_index(EXAMPLE), Some(1));
+    assert_eq!(page::example_answers(EXAMPLE), ["24000", "45000"]);
+    assert_eq!(page::example_index(SOLVED), None);
+}
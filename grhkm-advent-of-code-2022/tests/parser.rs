@@ -0,0 +1,88 @@
+use advent_of_code_2022::parser::{self, ParseError};
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::solver;
+use pretty_assertions::assert_eq;
+
+const CONTENTS: &str = "1,2\n3;4\n";
+
+#[test]
+fn test_locate_slices() {
+    let line = CONTENTS.lines().nth(1).unwrap();
+    let err = parser::split_once(CONTENTS, line, ",").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError {
+            line: 2,
+            column: 4,
+            text: "3;4".to_string(),
+            msg: "Expected \",\"".to_string(),
+        }
+    );
+    assert_eq!(err.to_string(), "line 2, column 4: Expected \",\"");
+    assert_eq!(err.caret(), "2 | 3;4\n  |    ^");
+
+    let (_, y) = parser::split_once(CONTENTS, "1,2", ",").unwrap();
+    assert_eq!(parser::token::<u8>(CONTENTS, y), Ok(2));
+    let err = parser::token::<u8>(CONTENTS, &line[1..]).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(
+        err.msg,
+        "Failed to parse \";4\": invalid digit found in string"
+    );
+
+    // owned strings can only be searched for
+    let owned = String::from("3;");
+    let err = ParseError::new(CONTENTS, &owned, "Bad");
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn test_day_parse_errors() {
+    let err = solver::solve("2-4,6-8\n2-3", Puzzle::new(2022, 4)).unwrap_err();
+    let err = err.parse_error().unwrap();
+    assert_eq!((err.line, err.column), (2, 4));
+    assert_eq!(err.caret(), "2 | 2-3\n  |    ^");
+
+    let err = solver::solve("R 4\nX 2", Puzzle::new(2022, 9)).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: Unknown direction \"X\"");
+
+    let err = solver::solve("1=-\n12a", Puzzle::new(2022, 25)).unwrap_err();
+    assert_eq!(
+        (
+            err.parse_error().unwrap().line,
+            err.parse_error().unwrap().column
+        ),
+        (2, 3)
+    );
+
+    // day 13 packets come in pairs of well-formed lists of numbers that fit in a usize
+    let day_13 = |contents: &str| solver::solve(contents, Puzzle::new(2022, 13));
+    assert_eq!(
+        day_13("[1,[2]]\n[[1],3]\n\n[]\n[[]]").unwrap(),
+        ("3".to_string(), "30".to_string())
+    );
+    let err = day_13("[1,2]\n[3]\n\n[4]").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 4, column 1: Expected packets in pairs"
+    );
+    let err = day_13("[1,2]\n[99999999999999999999]").unwrap_err();
+    assert_eq!(
+        err.parse_error().unwrap().msg,
+        "Failed to parse \"99999999999999999999\": number too large to fit in target type"
+    );
+    assert_eq!(err.parse_error().unwrap().column, 2);
+    for (packet, column) in [
+        ("[1[2]]", 3),
+        ("[1,,2]", 4),
+        ("[1]]", 4),
+        ("[1][2]", 4),
+        ("[[1]", 5),
+    ] {
+        let err = day_13(&format!("[1]\n{packet}")).unwrap_err();
+        assert_eq!(
+            (packet, err.parse_error().unwrap().column),
+            (packet, column)
+        );
+    }
+}
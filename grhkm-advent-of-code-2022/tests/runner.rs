@@ -0,0 +1,123 @@
+use advent_of_code_2022::error::Error;
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::runner::{self, Report, Status};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+#[test]
+fn test_run_statuses() {
+    let report = runner::run(Puzzle::new(2022, 0), "1\n2", None, None);
+    assert_eq!(report.status, Status::Ok);
+    assert_eq!(report.output.unwrap().part1.unwrap().answer, "3");
+
+    // day 22 is still a `todo!()`
+    let report = runner::run(Puzzle::new(2022, 22), "", None, None);
+    assert_eq!(report.status, Status::Unimplemented);
+    assert!(!report.status.is_failure());
+
+    // parses fine, but there's no root monkey to evaluate
+    let report = runner::run(Puzzle::new(2022, 21), "aaaa: 5", None, None);
+    assert!(matches!(report.status, Status::Panicked(_)));
+    assert!(report.status.is_failure());
+    assert!(report.output.is_none());
+
+    let report = runner::run(Puzzle::new(2022, 0), "1\none", None, None);
+    let Status::InvalidInput(e) = &report.status else {
+        panic!("Expected invalid input, got {:?}", report.status);
+    };
+    assert_eq!((e.line, e.column), (2, 1));
+    assert!(report.status.is_failure());
+    assert!(report.output.is_none());
+}
+
+#[test]
+fn test_summary_table_shows_bad_input() {
+    let reports = [runner::run(
+        Puzzle::new(2022, 4),
+        "2-4,6-8\n2-x,4-5",
+        None,
+        None,
+    )];
+    let table = runner::format_table(&reports);
+    assert_eq!(
+        table.lines().skip(2).collect::<Vec<_>>(),
+        [
+            "Year 2022 Day #04: Invalid input: line 2, column 3: Failed to parse \"x\": invalid digit found in string",
+            "2 | 2-x,4-5",
+            "  |   ^",
+        ]
+    );
+}
+
+#[test]
+fn test_summary_table() {
+    let reports = [
+        runner::run(Puzzle::new(2022, 0), "1\n2", None, None),
+        Report::fetch_failed(Puzzle::new(2022, 1), "Please log in".to_string()),
+    ];
+    let table = runner::format_table(&reports);
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].starts_with("Year 2022 Day #00 Ok"));
+    assert!(lines[1].contains(" 3                   30 "));
+    assert!(lines[2].contains("Failed to fetch"));
+    assert!(lines[2].ends_with(" -"));
+    assert_eq!(
+        lines[3],
+        "Year 2022 Day #01: Failed to fetch: Please log in"
+    );
+}
+
+#[test]
+fn test_run_timeout() {
+    // day 15 walks every column within reach of the sensors
+    let input = "Sensor at x=0, y=0: closest beacon is at x=1000000000, y=0";
+    let timeout = Duration::from_millis(50);
+    let report = runner::run(Puzzle::new(2022, 15), input, Some(1), Some(timeout));
+    assert_eq!(report.status, Status::TimedOut(timeout));
+    assert!(report.status.is_failure());
+
+    let report = runner::run(
+        Puzzle::new(2022, 0),
+        "1\n2",
+        None,
+        Some(Duration::from_secs(10)),
+    );
+    assert_eq!(report.status, Status::Ok);
+}
+
+#[test]
+fn test_run_all_keeps_order_and_isolates_panics() {
+    let day_0 = Puzzle::new(2022, 0);
+    let day_21 = Puzzle::new(2022, 21);
+    let inputs = || {
+        vec![
+            (day_0, Ok("1".to_string())),
+            (day_21, Ok("aaaa: 5".to_string())),
+            (day_0, Err(Error::Usage("no input".to_string()))),
+            (day_0, Ok("2".to_string())),
+            (day_0, Ok("3".to_string())),
+        ]
+    };
+
+    for parallel in [false, true] {
+        let mut seen = vec![];
+        let reports = runner::run_all(inputs(), None, None, parallel, |report| {
+            seen.push(report.status.label())
+        });
+        let part1 = reports
+            .iter()
+            .map(|r| {
+                r.output
+                    .as_ref()
+                    .map(|o| o.part1.as_ref().unwrap().answer.as_str())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(part1, [Some("1"), None, None, Some("2"), Some("3")]);
+        assert_eq!(
+            seen,
+            ["Ok", "Panicked", "Failed to fetch", "Ok", "Ok"],
+            "parallel: {parallel}"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use advent_of_code_2022::config::Config;
+use advent_of_code_2022::download::{self, Outcome};
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::scaffold;
+use common::mock_server::{mock_fetcher, MockServer};
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::io;
+use tempfile::TempDir;
+
+// A copy of the files the scaffolder touches, trimmed to two days
+fn crate_root() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("src/solutions/y2022")).unwrap();
+    fs::create_dir_all(root.join("tests")).unwrap();
+    fs::write(
+        root.join("src/solutions/mod.rs"),
+        "macro_rules! register_solutions {}\n\npub mod y2022;\n\n\
+         pub fn register(registry: &mut Registry) {\n    y2022::register(registry);\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("src/solutions/y2022/mod.rs"),
+        "register_solutions! {\n    2022;\n    1 => day_01::Day01,\n    3 => day_03::Day03,\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("tests/tests.rs"),
+        "mod y2022 {\n    solutions_tests! {\n        2022;\n        \
+         test_day_01: (1, (\"1\", \"2\")),\n        test_day_03: (3, (\"3\", \"4\")),\n    }\n}\n",
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn test_new_day() {
+    let dir = crate_root();
+    let root = dir.path();
+    let changed = scaffold::new_day(root, Puzzle::new(2022, 2), &Config::default()).unwrap();
+    assert_eq!(changed.len(), 4);
+
+    assert_eq!(
+        fs::read_to_string(root.join("src/solutions/y2022/mod.rs")).unwrap(),
+        "register_solutions! {\n    2022;\n    1 => day_01::Day01,\n    2 => day_02::Day02,\n    \
+         3 => day_03::Day03,\n}\n"
+    );
+    let module = fs::read_to_string(root.join("src/solutions/y2022/day_02.rs")).unwrap();
+    assert!(module.contains("impl Solution for Day02 {"));
+    assert!(fs::read_to_string(root.join("tests/tests.rs"))
+        .unwrap()
+        .contains(
+            "(\"1\", \"2\")),\n        #[ignore = \"pending\"]\n        \
+             test_day_02: (2, (\"\", \"\")),\n        test_day_03"
+        ));
+    assert!(!root.join("input/2022/day_02.in").exists());
+    assert_eq!(
+        fs::read_to_string(root.join("input/2022/sample/day_02.in")).unwrap(),
+        ""
+    );
+
+    let err = scaffold::new_day(root, Puzzle::new(2022, 2), &Config::default()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+}
+
+#[test]
+fn test_new_year() {
+    let dir = crate_root();
+    let root = dir.path();
+    scaffold::new_day(root, Puzzle::new(2023, 1), &Config::default()).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(root.join("src/solutions/mod.rs")).unwrap(),
+        "macro_rules! register_solutions {}\n\npub mod y2022;\npub mod y2023;\n\n\
+         pub fn register(registry: &mut Registry) {\n    y2022::register(registry);\n    \
+         y2023::register(registry);\n}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("src/solutions/y2023/mod.rs")).unwrap(),
+        "use crate::solver::Registry;\n\nregister_solutions! {\n    2023;\n    \
+         1 => day_01::Day01,\n}\n"
+    );
+    let tests = fs::read_to_string(root.join("tests/tests.rs")).unwrap();
+    assert!(tests.contains(
+        "solutions_tests! {\n        2023;\n        #[ignore = \"pending\"]\n        \
+         test_day_01: (1, (\"\", \"\")),\n    }"
+    ));
+}
+
+#[test]
+fn test_new_day_then_fetch() {
+    let dir = crate_root();
+    let root = dir.path();
+    let puzzle = Puzzle::new(2022, 2);
+    let config = Config {
+        input_dir: root.join("input"),
+        ..Config::default()
+    };
+    scaffold::new_day(root, puzzle, &config).unwrap();
+
+    // the scaffolded day still gets its real input
+    let server = MockServer::start().input(puzzle, "A Y\nB X\nC Z");
+    let fetcher = mock_fetcher(&server);
+    let outcomes = download::download(&fetcher, &config, 2022, &[2]).unwrap();
+    assert!(matches!(outcomes[..], [(_, Outcome::Fetched)]));
+    assert_eq!(
+        fs::read_to_string(config.input_path(puzzle)).unwrap(),
+        "A Y\nB X\nC Z"
+    );
+}
+
+#[test]
+fn test_sample_test_entry() {
+    let answers = ["CMZ".to_string(), "\n#.\n".to_string()];
+    assert_eq!(
+        scaffold::sample_test_entry(5, &answers),
+        r#"test_day_05: (5, ("CMZ", "\n#.\n")),"#
+    );
+    assert_eq!(
+        scaffold::sample_test_entry(12, &answers[..1]),
+        r#"test_day_12: (12, ("CMZ", "")),"#
+    );
+}
@@ -0,0 +1,46 @@
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::solver;
+use pretty_assertions::assert_eq;
+
+const PUZZLE: Puzzle = Puzzle::new(2022, 0);
+const INPUT: &str = "1\n2\n3";
+
+fn answers(part: Option<usize>) -> (Option<String>, Option<String>) {
+    let output = solver::run_puzzle(INPUT, PUZZLE, part).unwrap();
+    (
+        output.part1.map(|a| a.answer),
+        output.part2.map(|a| a.answer),
+    )
+}
+
+#[test]
+fn test_run_selected_parts() {
+    assert_eq!(
+        answers(None),
+        (Some("6".to_string()), Some("60".to_string()))
+    );
+    assert_eq!(answers(Some(1)), (Some("6".to_string()), None));
+    assert_eq!(answers(Some(2)), (None, Some("60".to_string())));
+    assert_eq!(
+        solver::solve(INPUT, PUZZLE).unwrap(),
+        ("6".to_string(), "60".to_string())
+    );
+    assert_eq!(
+        solver::solve(INPUT, Puzzle::new(2022, 26))
+            .unwrap_err()
+            .to_string(),
+        "No solution registered for Year 2022 Day #26"
+    );
+}
+
+#[test]
+fn test_big_integer_answer() {
+    // part 2 is ten times the sum, which no longer fits in a u64
+    let input = u64::MAX.to_string();
+    assert_eq!(
+        solver::solve(&input, PUZZLE).unwrap(),
+        (input.clone(), "184467440737095516150".to_string())
+    );
+    let output = solver::run_puzzle(&input, PUZZLE, Some(2)).unwrap();
+    assert_eq!(output.part2.unwrap().answer, "184467440737095516150");
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::runner::Status;
+use advent_of_code_2022::stats::{self, Check, PartTime};
+use common::mock_server::{mock_fetcher, MockServer};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+const CALENDAR: &str = include_str!("fixtures/calendar_2022.html");
+const PERSONAL_TIMES: &str = include_str!("fixtures/personal_times_2022.html");
+
+const DAY_1: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+const DAY_19: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.";
+
+fn part(time: &str, rank: usize, score: usize) -> Option<PartTime> {
+    Some(PartTime {
+        time: time.to_string(),
+        rank,
+        score,
+    })
+}
+
+#[test]
+fn test_personal_times() {
+    let times = stats::personal_times(PERSONAL_TIMES);
+    assert_eq!(times.keys().copied().collect::<Vec<_>>(), [1, 2, 19, 22]);
+    assert_eq!(
+        times[&1],
+        [part("00:02:30", 95, 6), part("00:03:40", 80, 21)]
+    );
+    assert_eq!(times[&19], [part("02:00:00", 3000, 0), None]);
+    assert_eq!(
+        times[&22],
+        [part("01:02:03", 2000, 0), part(">24h", 30000, 0)]
+    );
+
+    assert!(stats::personal_times("<p>Not logged in</p>").is_empty());
+}
+
+#[test]
+fn test_check() {
+    let timeout = Some(Duration::from_secs(10));
+    let check =
+        |day, level, contents| stats::check(Puzzle::new(2022, day), level, contents, timeout);
+    assert_eq!(check(1, 2, Some(DAY_1)), Check::Working);
+    // still `todo!()`, whatever the input
+    assert_eq!(check(22, 1, Some("")), Check::Missing);
+    assert_eq!(check(19, 1, Some(DAY_19)), Check::Missing);
+    assert!(matches!(
+        check(1, 1, Some("not a number")),
+        Check::Broken(Status::InvalidInput(_))
+    ));
+    assert_eq!(check(2, 1, None), Check::Unchecked);
+    assert_eq!(
+        stats::check(Puzzle::new(2015, 1), 1, None, timeout),
+        Check::Missing
+    );
+}
+
+#[test]
+fn test_stats() {
+    let contents = |puzzle: Puzzle| match puzzle.day {
+        1 => Some(DAY_1.to_string()),
+        19 => Some(DAY_19.to_string()),
+        22 => Some(String::new()),
+        _ => None,
+    };
+    let stats = stats::collect(
+        2022,
+        CALENDAR,
+        PERSONAL_TIMES,
+        contents,
+        Some(Duration::from_secs(10)),
+    );
+
+    let days = stats
+        .iter()
+        .map(|day| (day.day, day.stars, day.flagged()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        days,
+        [
+            (1, 2, false),
+            (2, 2, false),
+            (3, 0, false),
+            (19, 1, true),
+            (22, 2, true)
+        ]
+    );
+    assert_eq!(stats[3].checks, [Check::Missing]);
+    assert_eq!(stats[2].times, [None, None]);
+
+    assert_eq!(
+        stats::format(&stats),
+        "Day  Stars    Part 1   Rank Score    Part 2   Rank Score  Solution\n  \
+           1  **     00:02:30     95     6  00:03:40     80    21  Ok\n  \
+           2  **     00:05:10    900     0  00:08:00    850     0  Part 1: No input to check with, Part 2: No input to check with\n  \
+           3                -      -     -         -      -     -\n \
+          19  *      02:00:00   3000     0         -      -     -  Part 1: No solution\n \
+          22  **     01:02:03   2000     0      >24h  30000     0  Part 1: No solution, Part 2: No solution\n\
+         \n\
+         Starred without a working solution: days 19, 22\n"
+    );
+}
+
+#[test]
+fn test_fetch_personal_times() {
+    let server = MockServer::start()
+        .solved(Puzzle::new(2022, 1), 1)
+        .solved(Puzzle::new(2022, 1), 2)
+        .solved(Puzzle::new(2022, 3), 1);
+    let fetcher = mock_fetcher(&server);
+
+    let times = stats::personal_times(&fetcher.personal_times(2022).unwrap());
+    assert_eq!(times.keys().copied().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(times[&3], [part("00:01:00", 1, 100), None]);
+    assert_eq!(server.requests(), ["GET /2022/leaderboard/self"]);
+}
@@ -0,0 +1,102 @@
+use advent_of_code_2022::submission::{Hint, Verdict};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+fn page(article: &str) -> String {
+    format!("<!DOCTYPE html>\n<html><body>\n<main>\n<article><p>{article}</p></article>\n</main>\n</body></html>")
+}
+
+#[test]
+fn test_correct() {
+    let body = page(
+        r#"That's the right answer!  You are <span class="day-success">one gold star</span> closer to collecting enough star fruit. <a href="/2022/day/1#part2">[Continue to Part Two]</a>"#,
+    );
+    assert_eq!(Verdict::parse(&body), Verdict::Correct);
+    assert_eq!(Verdict::parse(&body).exit_code(), 0);
+}
+
+#[test]
+fn test_wrong() {
+    let body = page(
+        r#"That's not the right answer.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2022/about">about page</a>.  Please wait one minute before trying again. <a href="/2022/day/1">[Return to Day 1]</a>"#,
+    );
+    assert_eq!(Verdict::parse(&body), Verdict::Wrong { hint: None });
+
+    let body = page(
+        r#"That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. <a href="/2022/day/1">[Return to Day 1]</a>"#,
+    );
+    assert_eq!(
+        Verdict::parse(&body),
+        Verdict::Wrong {
+            hint: Some(Hint::TooHigh)
+        }
+    );
+
+    let body = page(
+        r#"That's not the right answer; your answer is too low.  Please wait one minute before trying again. <a href="/2022/day/1">[Return to Day 1]</a>"#,
+    );
+    assert_eq!(
+        Verdict::parse(&body),
+        Verdict::Wrong {
+            hint: Some(Hint::TooLow)
+        }
+    );
+}
+
+#[test]
+fn test_rate_limited() {
+    let body = page(
+        r#"You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href="/2022/day/1">[Return to Day 1]</a>"#,
+    );
+    assert_eq!(
+        Verdict::parse(&body),
+        Verdict::RateLimited {
+            wait: Duration::from_secs(83)
+        }
+    );
+
+    let body = page(
+        r#"You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href="/2022/day/1">[Return to Day 1]</a>"#,
+    );
+    assert_eq!(
+        Verdict::parse(&body),
+        Verdict::RateLimited {
+            wait: Duration::from_secs(34)
+        }
+    );
+}
+
+#[test]
+fn test_level() {
+    // verbatim from the server, which sends it both for a finished part and for a
+    // part 2 whose part 1 isn't solved yet
+    let body = page(
+        r#"You don't seem to be solving the right level.  Did you already complete it? <a href="/2022/day/1">[Return to Day 1]</a>"#,
+    );
+    assert_eq!(Verdict::parse(&body), Verdict::WrongLevel);
+
+    // told apart by the answers shown on the day page
+    let verdict = Verdict::parse(&body);
+    assert_eq!(verdict.clone().resolve_level(2, 1), Verdict::WrongLevel);
+    assert_eq!(verdict.clone().resolve_level(2, 2), Verdict::AlreadySolved);
+    assert_eq!(verdict.resolve_level(1, 1), Verdict::AlreadySolved);
+    assert_eq!(Verdict::Correct.resolve_level(1, 1), Verdict::Correct);
+}
+
+#[test]
+fn test_exit_codes() {
+    let verdicts = [
+        Verdict::Correct,
+        Verdict::Wrong { hint: None },
+        Verdict::RateLimited {
+            wait: Duration::ZERO,
+        },
+        Verdict::AlreadySolved,
+        Verdict::WrongLevel,
+        Verdict::Unknown("".to_string()),
+    ];
+    let mut codes = verdicts.iter().map(Verdict::exit_code).collect::<Vec<_>>();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), verdicts.len());
+}
@@ -1,12 +1,15 @@
+#[cfg(sample_test)]
 #[macro_use]
 mod common;
 
 #[cfg(sample_test)]
-mod tests {
+mod y2022 {
     use super::common::utils::*;
+    use advent_of_code_2022::puzzle::Puzzle;
     use pretty_assertions::assert_eq;
 
     solutions_tests! {
+        2022;
         test_day_00: (0, ("15", "150")),
         test_day_01: (1, ("24000", "50000")),
         test_day_02: (2, ("15", "12")),
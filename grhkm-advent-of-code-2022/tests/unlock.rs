@@ -0,0 +1,127 @@
+mod common;
+
+use advent_of_code_2022::error::Error;
+use advent_of_code_2022::puzzle::Puzzle;
+use advent_of_code_2022::unlock::{self, Clock};
+use common::mock_server::{mock_fetcher, MockServer};
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Time that only moves when someone sleeps
+struct FakeClock {
+    now: Cell<SystemTime>,
+    sleeps: RefCell<Vec<Duration>>,
+}
+
+impl FakeClock {
+    fn at(now: SystemTime) -> FakeClock {
+        FakeClock {
+            now: Cell::new(now),
+            sleeps: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+        self.sleeps.borrow_mut().push(duration);
+    }
+}
+
+#[test]
+fn test_unlock_time() {
+    // 2022-12-01T05:00:00Z and 2015-12-25T05:00:00Z
+    let secs = |puzzle| {
+        unlock::unlock_time(puzzle)
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    };
+    assert_eq!(secs(Puzzle::new(2022, 1)), 1669870800);
+    assert_eq!(secs(Puzzle::new(2015, 25)), 1451019600);
+}
+
+#[test]
+fn test_format_countdown() {
+    assert_eq!(
+        unlock::format_countdown(Duration::from_secs(59)),
+        "00:00:59"
+    );
+    assert_eq!(
+        unlock::format_countdown(Duration::from_millis(1500)),
+        "00:00:02"
+    );
+    assert_eq!(
+        unlock::format_countdown(Duration::from_secs(86400 + 3723)),
+        "1d 01:02:03"
+    );
+}
+
+#[test]
+fn test_wait_then_fetch() {
+    let puzzle = Puzzle::new(2022, 5);
+    let unlock_time = unlock::unlock_time(puzzle);
+    let clock = FakeClock::at(unlock_time - Duration::from_millis(2500));
+
+    let mut ticks = Vec::new();
+    unlock::wait(puzzle, &clock, |left| {
+        ticks.push(unlock::format_countdown(left))
+    });
+    assert_eq!(ticks, ["00:00:03", "00:00:02", "00:00:01"]);
+    assert_eq!(clock.now(), unlock_time);
+
+    // already unlocked, so no more waiting
+    unlock::wait(puzzle, &clock, |_| panic!("Should not wait"));
+    assert_eq!(clock.sleeps.borrow().len(), 3);
+
+    let server = MockServer::start().input(puzzle, "1\n2\n3");
+    let fetcher = mock_fetcher(&server);
+    let input = unlock::retry(&clock, 3, Duration::from_secs(1), || fetcher.fetch(puzzle));
+    assert_eq!(input.unwrap(), "1\n2\n3");
+    assert_eq!(server.requests(), ["GET /2022/day/5/input"]);
+}
+
+#[test]
+fn test_retry_until_unlocked() {
+    let clock = FakeClock::at(UNIX_EPOCH);
+    let not_found = || Error::Response {
+        status: StatusCode::NOT_FOUND,
+        msg: "Please don't repeatedly request this endpoint before it unlocks!".to_string(),
+    };
+
+    let mut calls = 0;
+    let result = unlock::retry(&clock, 5, Duration::from_secs(1), || {
+        calls += 1;
+        if calls < 3 {
+            Err(not_found())
+        } else {
+            Ok(calls)
+        }
+    });
+    assert_eq!(result.unwrap(), 3);
+    assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(2));
+
+    // gives up after the last attempt, and on anything but a locked puzzle
+    let mut calls = 0;
+    let result: Result<(), _> = unlock::retry(&clock, 2, Duration::from_secs(1), || {
+        calls += 1;
+        Err(not_found())
+    });
+    assert_eq!(result.unwrap_err().status(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(calls, 2);
+
+    let mut calls = 0;
+    let result: Result<(), _> = unlock::retry(&clock, 5, Duration::from_secs(1), || {
+        calls += 1;
+        Err(Error::Usage("No session cookie".to_string()))
+    });
+    assert!(result.is_err());
+    assert_eq!(calls, 1);
+}
@@ -298,6 +298,7 @@ impl<T> Poly<T>
 where
     T: Numeric,
 {
+    #[allow(clippy::ptr_arg)]
     pub fn new(coef: &Vec<T>) -> Poly<T> {
         if coef.is_empty() {
             return Poly {
@@ -425,7 +426,7 @@ where
         let neg_factors = factors.clone();
         let neg_factors = neg_factors.iter().rev().map(|c| c.neg());
 
-        let mut res = (neg_factors.chain(factors.into_iter()))
+        let mut res = (neg_factors.chain(factors))
             .filter(|r| self.eval_as(*r).is_zero())
             .collect::<Vec<T>>();
         if coef_first.is_zero() {